@@ -0,0 +1,96 @@
+use std::{path::PathBuf, str::FromStr};
+
+use bevy::prelude::*;
+
+pub const USAGE: &str = "Usage: bevy-ball-game [--headless] [--levels] [--seed N] [--max-frames N] [--record FILE] [--replay FILE] [--bench-collisions]";
+
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LaunchArgs {
+    pub headless: bool,
+    pub bench_collisions: bool,
+    pub levels: bool,
+    pub max_frames: Option<u32>,
+    pub seed: Option<u64>,
+    pub record: Option<PathBuf>,
+    pub replay: Option<PathBuf>
+}
+
+impl LaunchArgs {
+    /// Parses the command line, exiting with the usage text if it can't be understood.
+    pub fn from_env() -> LaunchArgs {
+        LaunchArgs::parse(std::env::args().skip(1)).unwrap_or_else(|error| {
+            eprintln!("{}\n{}", error, USAGE);
+            std::process::exit(2);
+        })
+    }
+
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<LaunchArgs, String> {
+        let mut launch_args = LaunchArgs::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--headless" => launch_args.headless = true,
+                "--bench-collisions" => launch_args.bench_collisions = true,
+                "--levels" => launch_args.levels = true,
+                "--max-frames" => launch_args.max_frames = Some(number(&mut args, &arg)?),
+                "--seed" => launch_args.seed = Some(number(&mut args, &arg)?),
+                "--record" => launch_args.record = Some(PathBuf::from(value(&mut args, &arg)?)),
+                "--replay" => launch_args.replay = Some(PathBuf::from(value(&mut args, &arg)?)),
+                _ => return Err(format!("Unknown argument: {}", arg))
+            }
+        }
+        Ok(launch_args)
+    }
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{} needs a value", flag))
+}
+
+fn number<T: FromStr>(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<T, String> {
+    let value = value(args, flag)?;
+    value.parse().map_err(|_| format!("{} needs a whole number, got {}", flag, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<LaunchArgs, String> {
+        LaunchArgs::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn no_arguments_give_the_defaults() {
+        assert_eq!(parse(&[]), Ok(LaunchArgs::default()));
+    }
+
+    #[test]
+    fn reads_flags_and_values() {
+        let launch_args = parse(&["--headless", "--levels", "--seed", "42", "--max-frames", "600", "--record", "run.ron"]).unwrap();
+
+        assert!(launch_args.headless && launch_args.levels && !launch_args.bench_collisions);
+        assert_eq!(launch_args.seed, Some(42));
+        assert_eq!(launch_args.max_frames, Some(600));
+        assert_eq!(launch_args.record, Some(PathBuf::from("run.ron")));
+        assert_eq!(launch_args.replay, None);
+    }
+
+    #[test]
+    fn rejects_unknown_arguments() {
+        assert_eq!(parse(&["--headless", "--fast"]), Err("Unknown argument: --fast".to_string()));
+    }
+
+    #[test]
+    fn rejects_values_that_are_not_whole_numbers() {
+        assert_eq!(parse(&["--seed", "abc"]), Err("--seed needs a whole number, got abc".to_string()));
+        assert_eq!(parse(&["--seed", "-1"]), Err("--seed needs a whole number, got -1".to_string()));
+        assert_eq!(parse(&["--max-frames", "1.5"]), Err("--max-frames needs a whole number, got 1.5".to_string()));
+    }
+
+    #[test]
+    fn rejects_missing_values() {
+        assert_eq!(parse(&["--seed"]), Err("--seed needs a value".to_string()));
+        assert_eq!(parse(&["--headless", "--replay"]), Err("--replay needs a value".to_string()));
+    }
+}
@@ -1,6 +1,31 @@
 use bevy::ecs::event::Event;
 
+use crate::game::collision::resources::Contact;
+
 #[derive(Event)]
 pub struct GameOver {
     pub score: u32
-}
\ No newline at end of file
+}
+
+/// Sent when an edited config file has been loaded into `GameConfig` mid-session.
+#[derive(Event)]
+pub struct GameConfigReloaded {}
+
+#[derive(Event)]
+pub struct StarCollected {}
+
+/// Sent when an enemy gets through to the player, costing a life.
+#[derive(Event)]
+pub struct PlayerHit {}
+
+/// Sent the first fixed step two colliders whose layers detect each other touch.
+#[derive(Event)]
+pub struct CollisionStarted {
+    pub contact: Contact
+}
+
+/// Sent the first fixed step two colliders that were touching no longer do, including when one was despawned.
+#[derive(Event)]
+pub struct CollisionEnded {
+    pub contact: Contact
+}
@@ -0,0 +1,67 @@
+use std::time::Instant;
+
+use bevy::prelude::*;
+
+use super::{BENCHMARK_ENTITY_COUNTS, BENCHMARK_RADIUS, BENCHMARK_RUNS};
+use super::components::Collider;
+use super::resources::{CollisionLayer, SpatialHash};
+use crate::game::resources::{GameRng, Playfield};
+
+/// Finds every overlapping pair among a growing number of balls spread over the playfield, once by
+/// testing each pair and once through `SpatialHash`, and prints the average time each approach takes.
+pub fn run() {
+    let playfield = Playfield::default();
+    println!("{:>8} {:>17} {:>17} {:>9}", "entities", "brute force (ms)", "spatial hash (ms)", "overlaps");
+
+    for count in BENCHMARK_ENTITY_COUNTS {
+        let mut game_rng = GameRng::from_seed(count as u64);
+        let positions: Vec<Vec2> = (0..count)
+            .map(|_| playfield.inner_point_at(game_rng.random_fraction(), BENCHMARK_RADIUS * 2.0))
+            .collect();
+
+        let (brute_force_overlaps, brute_force_ms) = time(|| brute_force_overlaps(&positions));
+        let mut spatial_hash = SpatialHash::default();
+        let (spatial_hash_overlaps, spatial_hash_ms) = time(|| spatial_hash_overlaps(&mut spatial_hash, &positions));
+
+        if brute_force_overlaps != spatial_hash_overlaps {
+            println!("Mismatch at {} entities: brute force found {} overlaps, spatial hash {}", count, brute_force_overlaps, spatial_hash_overlaps);
+        }
+        println!("{:>8} {:>17.3} {:>17.3} {:>9}", count, brute_force_ms, spatial_hash_ms, spatial_hash_overlaps);
+    }
+}
+
+fn time(mut f: impl FnMut() -> usize) -> (usize, f64) {
+    let start = Instant::now();
+    let mut result = 0;
+    for _ in 0..BENCHMARK_RUNS {
+        result = f();
+    }
+    (result, start.elapsed().as_secs_f64() * 1000.0 / BENCHMARK_RUNS as f64)
+}
+
+fn brute_force_overlaps(positions: &[Vec2]) -> usize {
+    let mut overlaps = 0;
+    for (index, position) in positions.iter().enumerate() {
+        for other in &positions[index + 1..] {
+            if position.distance(*other) < BENCHMARK_RADIUS * 2.0 { overlaps += 1; }
+        }
+    }
+    overlaps
+}
+
+/// Rebuilds the hash like every fixed step does, then looks up each ball's neighbours.
+fn spatial_hash_overlaps(spatial_hash: &mut SpatialHash, positions: &[Vec2]) -> usize {
+    let collider = Collider::circle(BENCHMARK_RADIUS, CollisionLayer::Enemy);
+    spatial_hash.clear();
+    for (index, position) in positions.iter().enumerate() {
+        spatial_hash.insert(Entity::from_raw(index as u32), *position, collider);
+    }
+
+    let mut overlaps = 0;
+    for (index, position) in positions.iter().enumerate() {
+        overlaps += spatial_hash.overlapping(*position, &collider, CollisionLayer::Enemy)
+            .filter(|entry| entry.entity.index() as usize > index)
+            .count();
+    }
+    overlaps
+}
@@ -0,0 +1,144 @@
+use bevy::prelude::*;
+
+use super::resources::CollisionLayer;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColliderShape {
+    Circle { radius: f32 },
+    /// Axis-aligned box, given by half its width and height.
+    Aabb { half_extents: Vec2 }
+}
+
+/// Shape an entity collides with, centered on its translation.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Collider {
+    pub shape: ColliderShape,
+    pub layer: CollisionLayer
+}
+
+impl Collider {
+    pub fn circle(radius: f32, layer: CollisionLayer) -> Collider {
+        Collider { shape: ColliderShape::Circle { radius }, layer }
+    }
+
+    /// Nothing on the field is boxy yet; every sprite is round.
+    #[allow(dead_code)]
+    pub fn aabb(half_extents: Vec2, layer: CollisionLayer) -> Collider {
+        Collider { shape: ColliderShape::Aabb { half_extents }, layer }
+    }
+
+    /// Scales the shape to fit a sprite `size` wide and high.
+    pub fn resize(&mut self, size: f32) {
+        self.shape = match self.shape {
+            ColliderShape::Circle { .. } => ColliderShape::Circle { radius: size / 2.0 },
+            ColliderShape::Aabb { .. } => ColliderShape::Aabb { half_extents: Vec2::splat(size / 2.0) }
+        };
+    }
+
+    /// Radius of the smallest circle around the center that contains the whole shape.
+    pub fn bounding_radius(&self) -> f32 {
+        match self.shape {
+            ColliderShape::Circle { radius } => radius,
+            ColliderShape::Aabb { half_extents } => half_extents.length()
+        }
+    }
+
+    /// Whether this collider at `position` touches `other` at `other_position`.
+    pub fn overlaps(&self, position: Vec2, other: &Collider, other_position: Vec2) -> bool {
+        match (self.shape, other.shape) {
+            (ColliderShape::Circle { radius }, ColliderShape::Circle { radius: other_radius }) => {
+                position.distance(other_position) < radius + other_radius
+            }
+            (ColliderShape::Aabb { half_extents }, ColliderShape::Aabb { half_extents: other_half_extents }) => {
+                let gap = (position - other_position).abs() - half_extents - other_half_extents;
+                gap.x < 0.0 && gap.y < 0.0
+            }
+            (ColliderShape::Circle { radius }, ColliderShape::Aabb { half_extents }) => {
+                circle_overlaps_aabb(position, radius, other_position, half_extents)
+            }
+            (ColliderShape::Aabb { half_extents }, ColliderShape::Circle { radius }) => {
+                circle_overlaps_aabb(other_position, radius, position, half_extents)
+            }
+        }
+    }
+}
+
+fn circle_overlaps_aabb(center: Vec2, radius: f32, box_center: Vec2, half_extents: Vec2) -> bool {
+    let closest = center.clamp(box_center - half_extents, box_center + half_extents);
+    center.distance(closest) < radius
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYER: CollisionLayer = CollisionLayer::Enemy;
+
+    #[test]
+    fn circles_overlap_closer_than_their_radii() {
+        let circle = Collider::circle(10.0, LAYER);
+        let other = Collider::circle(5.0, LAYER);
+
+        assert!(circle.overlaps(Vec2::ZERO, &other, Vec2::new(14.9, 0.0)));
+        assert!(!circle.overlaps(Vec2::ZERO, &other, Vec2::new(15.0, 0.0)));
+        assert!(!circle.overlaps(Vec2::ZERO, &other, Vec2::new(11.0, 11.0)));
+    }
+
+    #[test]
+    fn boxes_overlap_only_on_both_axes() {
+        let aabb = Collider::aabb(Vec2::new(10.0, 5.0), LAYER);
+        let other = Collider::aabb(Vec2::splat(5.0), LAYER);
+
+        assert!(aabb.overlaps(Vec2::ZERO, &other, Vec2::new(14.9, 9.9)));
+        assert!(!aabb.overlaps(Vec2::ZERO, &other, Vec2::new(15.0, 0.0)));
+        assert!(!aabb.overlaps(Vec2::ZERO, &other, Vec2::new(0.0, 10.0)));
+    }
+
+    #[test]
+    fn circle_misses_box_corner_inside_its_bounds() {
+        let circle = Collider::circle(10.0, LAYER);
+        let aabb = Collider::aabb(Vec2::splat(10.0), LAYER);
+
+        // Within reach on each axis, but the corner is 11.3 away from the circle's center.
+        assert!(!circle.overlaps(Vec2::new(18.0, 18.0), &aabb, Vec2::ZERO));
+        assert!(circle.overlaps(Vec2::new(16.0, 16.0), &aabb, Vec2::ZERO));
+        assert!(circle.overlaps(Vec2::new(19.9, 0.0), &aabb, Vec2::ZERO));
+        assert!(circle.overlaps(Vec2::new(2.0, 3.0), &aabb, Vec2::ZERO));
+    }
+
+    #[test]
+    fn overlaps_is_symmetric() {
+        let colliders = [
+            Collider::circle(8.0, LAYER),
+            Collider::circle(3.0, LAYER),
+            Collider::aabb(Vec2::new(6.0, 2.0), LAYER),
+            Collider::aabb(Vec2::splat(4.0), LAYER)
+        ];
+
+        for step in 0..400 {
+            let offset = Vec2::new((step % 20) as f32 - 10.0, (step / 20) as f32 - 10.0) * 1.5;
+            for collider in &colliders {
+                for other in &colliders {
+                    assert_eq!(collider.overlaps(Vec2::ZERO, other, offset), other.overlaps(offset, collider, Vec2::ZERO));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bounding_radius_contains_the_shape() {
+        assert_eq!(Collider::circle(7.0, LAYER).bounding_radius(), 7.0);
+        assert_eq!(Collider::aabb(Vec2::new(3.0, 4.0), LAYER).bounding_radius(), 5.0);
+    }
+
+    #[test]
+    fn resize_keeps_the_shape_and_layer() {
+        let mut circle = Collider::circle(7.0, CollisionLayer::Star);
+        circle.resize(30.0);
+        assert_eq!(circle, Collider::circle(15.0, CollisionLayer::Star));
+
+        let mut aabb = Collider::aabb(Vec2::new(3.0, 4.0), CollisionLayer::Star);
+        aabb.resize(30.0);
+        assert_eq!(aabb, Collider::aabb(Vec2::splat(15.0), CollisionLayer::Star));
+    }
+}
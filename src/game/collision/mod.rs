@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+pub mod benchmark;
+pub mod components;
+pub mod resources;
+mod systems;
+
+use resources::{Contacts, SpatialHash};
+use systems::*;
+
+use crate::events::{CollisionEnded, CollisionStarted};
+use crate::AppState;
+
+use super::SimulationState;
+use super::config::{ConfigSystemSet, resources::GameConfig};
+
+/// Width and height of a spatial hash cell. Roughly two enemy diameters, so most lookups touch a few cells.
+pub const SPATIAL_HASH_CELL_SIZE: f32 = 128.0;
+
+/// Ball counts `--bench-collisions` is run with.
+pub const BENCHMARK_ENTITY_COUNTS: [usize; 5] = [250, 1000, 2000, 4000, 8000];
+/// Timed repetitions per ball count; the average is reported.
+pub const BENCHMARK_RUNS: u32 = 3;
+pub const BENCHMARK_RADIUS: f32 = 8.0;
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum CollisionSystemSet {
+    /// Rebuilds the spatial hash from current positions and sends the collision events.
+    Detect,
+    /// Gameplay reactions to this step's collision events. They run right after detection,
+    /// since events sent during a fixed step may be gone by the next one.
+    React
+}
+
+/// Every entity with a `Collider` goes into a spatial hash that collision and proximity checks look
+/// their candidates up in, instead of testing every entity. Contacts between layers that detect each
+/// other are reported as `CollisionStarted` and `CollisionEnded` events.
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpatialHash>()
+            .init_resource::<Contacts>()
+            .add_event::<CollisionStarted>()
+            .add_event::<CollisionEnded>()
+            .add_systems(OnEnter(AppState::Game), rebuild_spatial_hash.in_set(CollisionSystemSet::Detect))
+            .add_systems(FixedUpdate, (rebuild_spatial_hash, detect_collisions)
+                .chain()
+                .in_set(CollisionSystemSet::Detect)
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::Running)))
+            .add_systems(PreUpdate, apply_collider_config
+                .after(ConfigSystemSet)
+                .run_if(in_state(AppState::Game))
+                .run_if(resource_changed::<GameConfig>()))
+            .add_systems(OnExit(AppState::Game), (clear_spatial_hash, clear_contacts));
+    }
+}
@@ -0,0 +1,192 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use super::SPATIAL_HASH_CELL_SIZE;
+use super::components::Collider;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollisionLayer {
+    Player,
+    Enemy,
+    Star,
+    PowerUp
+}
+
+impl CollisionLayer {
+    /// Layers whose colliders this one reports collisions with. Each pair of layers is listed
+    /// on one side only, so every contact is found once.
+    pub fn detects(self) -> &'static [CollisionLayer] {
+        match self {
+            CollisionLayer::Player => &[CollisionLayer::Enemy, CollisionLayer::Star, CollisionLayer::PowerUp],
+            _ => &[]
+        }
+    }
+}
+
+/// Two colliders touching. `entities` and `layers` line up, with the detecting layer first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact {
+    pub entities: [Entity; 2],
+    pub layers: [CollisionLayer; 2]
+}
+
+impl Contact {
+    /// The two entities in the order of the given layers, if this contact is between those layers.
+    pub fn between(&self, first: CollisionLayer, second: CollisionLayer) -> Option<(Entity, Entity)> {
+        if self.layers == [first, second] {
+            Some((self.entities[0], self.entities[1]))
+        } else if self.layers == [second, first] {
+            Some((self.entities[1], self.entities[0]))
+        } else {
+            None
+        }
+    }
+}
+
+/// Contacts found in the last fixed step, in the order they were detected. Compared against the next
+/// step's to tell which contacts started and which ended.
+#[derive(Resource, Default)]
+pub struct Contacts {
+    pub contacts: Vec<Contact>
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SpatialEntry {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub collider: Collider
+}
+
+/// Uniform grid bucketing colliders by the cell their center falls in. Rebuilt every fixed step,
+/// so positions are those at the start of the step.
+#[derive(Resource)]
+pub struct SpatialHash {
+    pub cell_size: f32,
+    cells: HashMap<IVec2, Vec<SpatialEntry>>,
+    max_radius: f32
+}
+
+impl Default for SpatialHash {
+    fn default() -> SpatialHash {
+        SpatialHash::new(SPATIAL_HASH_CELL_SIZE)
+    }
+}
+
+impl SpatialHash {
+    pub fn new(cell_size: f32) -> SpatialHash {
+        SpatialHash { cell_size, cells: HashMap::default(), max_radius: 0.0 }
+    }
+
+    /// Empties every cell while keeping its allocation for the next rebuild.
+    pub fn clear(&mut self) {
+        for entries in self.cells.values_mut() {
+            entries.clear();
+        }
+        self.max_radius = 0.0;
+    }
+
+    pub fn insert(&mut self, entity: Entity, position: Vec2, collider: Collider) {
+        self.max_radius = self.max_radius.max(collider.bounding_radius());
+        self.cells.entry(self.cell(position)).or_default().push(SpatialEntry { entity, position, collider });
+    }
+
+    pub fn cell(&self, position: Vec2) -> IVec2 {
+        (position / self.cell_size).floor().as_ivec2()
+    }
+
+    /// Entries of `layer` that may lie within `range` of `position`, widened by the largest bounding radius
+    /// so any collider reaching into that range is included. Callers still make the exact distance test.
+    pub fn query(&self, position: Vec2, range: f32, layer: CollisionLayer) -> impl Iterator<Item = &SpatialEntry> + '_ {
+        let reach = Vec2::splat(range + self.max_radius);
+        let (min, max) = (self.cell(position - reach), self.cell(position + reach));
+
+        (min.x..=max.x)
+            .flat_map(move |x| (min.y..=max.y).map(move |y| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .filter(move |entry| entry.collider.layer == layer)
+    }
+
+    /// Entries of `layer` whose collider touches `collider` placed at `position`.
+    pub fn overlapping<'a>(&'a self, position: Vec2, collider: &'a Collider, layer: CollisionLayer) -> impl Iterator<Item = &'a SpatialEntry> + 'a {
+        self.query(position, collider.bounding_radius(), layer)
+            .filter(move |entry| collider.overlaps(position, &entry.collider, entry.position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    /// Colliders of both layers scattered over, and a little past, a 1000 by 1000 area.
+    fn scattered(rng: &mut StdRng, count: u32) -> Vec<(Entity, Vec2, Collider)> {
+        (0..count).map(|index| {
+            let position = Vec2::new(rng.gen_range(-50.0..1050.0), rng.gen_range(-50.0..1050.0));
+            let layer = if index % 3 == 0 { CollisionLayer::Star } else { CollisionLayer::Enemy };
+            let collider = if index % 2 == 0 {
+                Collider::circle(rng.gen_range(1.0..60.0), layer)
+            } else {
+                Collider::aabb(Vec2::new(rng.gen_range(1.0..40.0), rng.gen_range(1.0..40.0)), layer)
+            };
+            (Entity::from_raw(index), position, collider)
+        }).collect()
+    }
+
+    fn spatial_hash(entries: &[(Entity, Vec2, Collider)]) -> SpatialHash {
+        let mut spatial_hash = SpatialHash::default();
+        for (entity, position, collider) in entries {
+            spatial_hash.insert(*entity, *position, *collider);
+        }
+        spatial_hash
+    }
+
+    #[test]
+    fn query_finds_every_collider_in_range() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let entries = scattered(&mut rng, 500);
+        let spatial_hash = spatial_hash(&entries);
+
+        for _ in 0..200 {
+            let position = Vec2::new(rng.gen_range(0.0..1000.0), rng.gen_range(0.0..1000.0));
+            let range = rng.gen_range(0.0..300.0);
+            let found: Vec<Entity> = spatial_hash.query(position, range, CollisionLayer::Enemy).map(|entry| entry.entity).collect();
+
+            for (entity, entry_position, collider) in &entries {
+                let in_range = position.distance(*entry_position) <= range + collider.bounding_radius();
+                if collider.layer == CollisionLayer::Enemy && in_range {
+                    assert!(found.contains(entity), "{:?} at {} missed from {} within {}", entity, entry_position, position, range);
+                }
+            }
+            assert!(found.iter().all(|entity| entries[entity.index() as usize].2.layer == CollisionLayer::Enemy));
+        }
+    }
+
+    #[test]
+    fn overlapping_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let entries = scattered(&mut rng, 500);
+        let spatial_hash = spatial_hash(&entries);
+
+        for (_, position, collider) in entries.iter().take(100) {
+            let mut found: Vec<Entity> = spatial_hash.overlapping(*position, collider, CollisionLayer::Enemy).map(|entry| entry.entity).collect();
+            let mut expected: Vec<Entity> = entries.iter()
+                .filter(|(_, other_position, other)| other.layer == CollisionLayer::Enemy && collider.overlaps(*position, other, *other_position))
+                .map(|(entity, _, _)| *entity)
+                .collect();
+
+            found.sort();
+            expected.sort();
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn clear_empties_every_cell() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut spatial_hash = spatial_hash(&scattered(&mut rng, 50));
+
+        spatial_hash.clear();
+        assert_eq!(spatial_hash.query(Vec2::splat(500.0), 2000.0, CollisionLayer::Enemy).count(), 0);
+    }
+}
@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use super::components::Collider;
+use super::resources::{CollisionLayer, Contact, Contacts, SpatialHash};
+use crate::events::{CollisionEnded, CollisionStarted};
+use crate::game::config::resources::GameConfig;
+
+pub fn rebuild_spatial_hash(
+    mut spatial_hash: ResMut<SpatialHash>,
+    collider_query: Query<(Entity, &Transform, &Collider)>
+) {
+    spatial_hash.clear();
+
+    for (entity, transform, collider) in collider_query.iter() {
+        spatial_hash.insert(entity, transform.translation.truncate(), *collider);
+    }
+}
+
+pub fn clear_spatial_hash(
+    mut spatial_hash: ResMut<SpatialHash>
+) {
+    spatial_hash.clear();
+}
+
+/// Finds every contact between colliders whose layers detect each other, then reports those that
+/// were not there last step as started and those that are gone as ended.
+pub fn detect_collisions(
+    mut contacts: ResMut<Contacts>,
+    mut collision_started_event_writer: EventWriter<CollisionStarted>,
+    mut collision_ended_event_writer: EventWriter<CollisionEnded>,
+    collider_query: Query<(Entity, &Transform, &Collider)>,
+    spatial_hash: Res<SpatialHash>
+) {
+    let mut current = Vec::new();
+
+    for (entity, transform, collider) in collider_query.iter() {
+        let position = transform.translation.truncate();
+        for layer in collider.layer.detects() {
+            for entry in spatial_hash.overlapping(position, collider, *layer) {
+                current.push(Contact { entities: [entity, entry.entity], layers: [collider.layer, *layer] });
+            }
+        }
+    }
+
+    for contact in current.iter().filter(|contact| !contacts.contacts.contains(contact)) {
+        collision_started_event_writer.send(CollisionStarted { contact: *contact });
+    }
+    for contact in contacts.contacts.iter().filter(|contact| !current.contains(contact)) {
+        collision_ended_event_writer.send(CollisionEnded { contact: *contact });
+    }
+    contacts.contacts = current;
+}
+
+pub fn clear_contacts(
+    mut contacts: ResMut<Contacts>
+) {
+    contacts.contacts.clear();
+}
+
+/// Brings the colliders and sprites of the player, stars and power-ups in line with an edited config.
+/// Enemies follow their own size in `apply_enemy_config`.
+pub fn apply_collider_config(
+    mut collider_query: Query<(&mut Collider, &mut Sprite)>,
+    game_config: Res<GameConfig>
+) {
+    for (mut collider, mut sprite) in collider_query.iter_mut() {
+        let size = match collider.layer {
+            CollisionLayer::Player => game_config.player.size,
+            CollisionLayer::Star => game_config.star.size,
+            CollisionLayer::PowerUp => game_config.power_up.size,
+            CollisionLayer::Enemy => continue
+        };
+        collider.resize(size);
+        sprite.custom_size = Some(Vec2::splat(size));
+    }
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use resources::{BaseGameConfig, Difficulty, GameConfig, GameConfigWatcher};
+use systems::*;
+
+use crate::events::GameConfigReloaded;
+use crate::replay::resources::ReplayPlayback;
+
+/// Location of the tuning file, relative to the `assets` folder.
+pub const GAME_CONFIG_PATH: &str = "config/game.ron";
+pub const MAX_SPAWNED_ENTITIES: usize = 500;
+/// Seconds of real time between checks of the config file for edits.
+pub const GAME_CONFIG_POLL_INTERVAL: f32 = 0.5;
+/// Lowest factor a linear difficulty curve can reach, so speeds and spawn times never hit zero.
+pub const MIN_DIRECTOR_FACTOR: f32 = 0.1;
+
+/// Systems deciding which `GameConfig` is in effect: on entering the game (after the seed is picked)
+/// and every frame before the simulation steps. Anything that sets up or adjusts the game from the
+/// config runs after it, so a replay can swap in the config it was recorded with.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct ConfigSystemSet;
+
+pub struct ConfigPlugin;
+
+impl Plugin for ConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameConfig>()
+            .init_resource::<BaseGameConfig>()
+            .init_resource::<Difficulty>()
+            .init_resource::<GameConfigWatcher>()
+            .add_event::<GameConfigReloaded>()
+            .add_systems(Startup, load_game_config)
+            .add_systems(PreUpdate, (
+                reload_game_config,
+                apply_difficulty.run_if(resource_changed::<BaseGameConfig>().or_else(resource_changed::<Difficulty>()))
+            ).chain().in_set(ConfigSystemSet).run_if(not(resource_exists::<ReplayPlayback>())));
+    }
+}
@@ -0,0 +1,622 @@
+use std::{fs, path::{Path, PathBuf}, time::SystemTime};
+
+use bevy::{prelude::*, asset::io::file::FileAssetReader};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::game::enemy::components::EnemyKind;
+use crate::game::helpers::helpers::pick_weighted;
+
+use super::{GAME_CONFIG_PATH, GAME_CONFIG_POLL_INTERVAL, MAX_SPAWNED_ENTITIES, MIN_DIRECTOR_FACTOR};
+
+/// Gameplay tuning values in effect for the current run: the config file scaled by the selected `Difficulty`.
+/// Every field is optional in the file; missing ones keep their default.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct GameConfig {
+    pub player: PlayerConfig,
+    pub enemy: EnemyConfig,
+    pub star: StarConfig,
+    pub power_up: PowerUpConfig,
+    pub director: DirectorConfig,
+    pub custom_difficulty: DifficultyScaling
+}
+
+/// The config file as read from disk, before the selected difficulty is applied.
+#[derive(Resource, Debug, Default)]
+pub struct BaseGameConfig {
+    pub config: GameConfig
+}
+
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Custom
+}
+
+impl Difficulty {
+    pub fn next(self) -> Difficulty {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Custom,
+            Difficulty::Custom => Difficulty::Easy
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+            Difficulty::Custom => "Custom"
+        }
+    }
+
+    /// Presets are fixed; `Custom` takes its factors from the `custom_difficulty` section of the config file.
+    pub fn scaling(self, game_config: &GameConfig) -> DifficultyScaling {
+        match self {
+            Difficulty::Easy => DifficultyScaling { enemy_count: 0.5, enemy_speed: 0.75, enemy_spawn_time: 1.5, star_spawn_time: 0.75 },
+            Difficulty::Normal => DifficultyScaling::default(),
+            Difficulty::Hard => DifficultyScaling { enemy_count: 1.5, enemy_speed: 1.3, enemy_spawn_time: 0.6, star_spawn_time: 1.5 },
+            Difficulty::Custom => game_config.custom_difficulty.clone()
+        }
+    }
+}
+
+/// How the difficulty ramps up during a run. A level is gained every `seconds_per_level` survived
+/// and every `stars_per_level` stars collected (0 turns the star part off), up to `max_level`.
+/// Each step is the change per level: with a linear curve a factor is `1 + step * level`,
+/// with an exponential one it is `(1 + step) ^ level`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DirectorConfig {
+    pub seconds_per_level: f32,
+    pub stars_per_level: u32,
+    pub max_level: u32,
+    pub curve: DifficultyCurve,
+    pub enemy_speed_step: f32,
+    pub enemy_spawn_time_step: f32,
+    pub star_spawn_time_step: f32
+}
+
+impl Default for DirectorConfig {
+    fn default() -> DirectorConfig {
+        DirectorConfig {
+            seconds_per_level: 20.0,
+            stars_per_level: 5,
+            max_level: 10,
+            curve: DifficultyCurve::Exponential,
+            enemy_speed_step: 0.08,
+            enemy_spawn_time_step: -0.08,
+            star_spawn_time_step: 0.1
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum DifficultyCurve {
+    Linear,
+    Exponential
+}
+
+impl DirectorConfig {
+    pub fn level(&self, elapsed: f32, stars_collected: u32) -> u32 {
+        let from_time = (elapsed / self.seconds_per_level) as u32;
+        let from_stars = stars_collected.checked_div(self.stars_per_level).unwrap_or(0);
+        (from_time + from_stars).min(self.max_level)
+    }
+
+    pub fn factor(&self, level: u32, step: f32) -> f32 {
+        match self.curve {
+            DifficultyCurve::Linear => (1.0 + step * level as f32).max(MIN_DIRECTOR_FACTOR),
+            DifficultyCurve::Exponential => (1.0 + step).powi(level as i32)
+        }
+    }
+}
+
+/// Factors applied to the base config. Spawn time factors above 1 mean less frequent spawns.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DifficultyScaling {
+    pub enemy_count: f32,
+    pub enemy_speed: f32,
+    pub enemy_spawn_time: f32,
+    pub star_spawn_time: f32
+}
+
+impl Default for DifficultyScaling {
+    fn default() -> DifficultyScaling {
+        DifficultyScaling { enemy_count: 1.0, enemy_speed: 1.0, enemy_spawn_time: 1.0, star_spawn_time: 1.0 }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PlayerConfig {
+    pub size: f32,
+    pub speed: f32,
+    pub lives: u32,
+    /// Seconds the player cannot be hit after respawning.
+    pub invulnerable_time: f32
+}
+
+impl Default for PlayerConfig {
+    fn default() -> PlayerConfig {
+        PlayerConfig { size: 64.0, speed: 500.0, lives: 3, invulnerable_time: 2.0 }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct EnemyConfig {
+    pub size: f32,
+    pub speed: f32,
+    pub count: usize,
+    pub spawn_time: f32,
+    /// Enemies never appear closer than this to the player's center.
+    pub safe_spawn_radius: f32,
+    /// Archetypes spawned outside level waves, each picked with a chance proportional to its weight.
+    pub kinds: Vec<(EnemyKind, u32)>,
+    /// Whether enemies bounce off each other instead of passing through.
+    pub collisions: bool,
+    pub chaser: ChaserConfig,
+    pub zig_zag: ZigZagConfig,
+    pub tank: TankConfig,
+    pub dart: DartConfig
+}
+
+impl Default for EnemyConfig {
+    fn default() -> EnemyConfig {
+        EnemyConfig {
+            size: 64.0,
+            speed: 200.0,
+            count: 6,
+            spawn_time: 3.0,
+            safe_spawn_radius: 200.0,
+            kinds: vec![(EnemyKind::Ball, 1)],
+            collisions: false,
+            chaser: ChaserConfig::default(),
+            zig_zag: ZigZagConfig::default(),
+            tank: TankConfig::default(),
+            dart: DartConfig::default()
+        }
+    }
+}
+
+impl EnemyConfig {
+    pub fn pick_kind(&self, rng: &mut impl Rng) -> EnemyKind {
+        pick_weighted(&self.kinds, |(_, weight)| *weight, rng).map_or(EnemyKind::default(), |(kind, _)| *kind)
+    }
+
+    /// Size and speed of an archetype as multiples of `size` and `speed`. A Ball is the baseline.
+    pub fn scales(&self, kind: EnemyKind) -> (f32, f32) {
+        match kind {
+            EnemyKind::Ball => (1.0, 1.0),
+            EnemyKind::Chaser => (self.chaser.size, self.chaser.speed),
+            EnemyKind::ZigZag => (self.zig_zag.size, self.zig_zag.speed),
+            EnemyKind::Tank => (self.tank.size, self.tank.speed),
+            EnemyKind::Dart => (self.dart.size, self.dart.speed)
+        }
+    }
+
+    pub fn kind_size(&self, kind: EnemyKind) -> f32 {
+        self.size * self.scales(kind).0
+    }
+
+    pub fn kind_speed(&self, kind: EnemyKind) -> f32 {
+        self.speed * self.scales(kind).1
+    }
+}
+
+/// Steers toward the player, turning by at most `turn_rate` radians per second.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ChaserConfig {
+    pub size: f32,
+    pub speed: f32,
+    pub turn_rate: f32
+}
+
+impl Default for ChaserConfig {
+    fn default() -> ChaserConfig {
+        ChaserConfig { size: 1.0, speed: 0.7, turn_rate: 1.5 }
+    }
+}
+
+/// Swings its heading by `swing` radians every `interval` seconds, alternating sides.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ZigZagConfig {
+    pub size: f32,
+    pub speed: f32,
+    pub interval: f32,
+    pub swing: f32
+}
+
+impl Default for ZigZagConfig {
+    fn default() -> ZigZagConfig {
+        ZigZagConfig { size: 1.0, speed: 1.0, interval: 0.6, swing: 1.0 }
+    }
+}
+
+/// Counts as `mass` times heavier in enemy collisions than its size alone makes it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct TankConfig {
+    pub size: f32,
+    pub speed: f32,
+    pub mass: f32
+}
+
+impl Default for TankConfig {
+    fn default() -> TankConfig {
+        TankConfig { size: 1.75, speed: 0.5, mass: 2.0 }
+    }
+}
+
+/// Aims straight at the player every time it bounces off a wall.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DartConfig {
+    pub size: f32,
+    pub speed: f32
+}
+
+impl Default for DartConfig {
+    fn default() -> DartConfig {
+        DartConfig { size: 0.6, speed: 1.8 }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct StarConfig {
+    pub size: f32,
+    pub count: usize,
+    pub spawn_time: f32,
+    /// Smallest distance kept between the centers of two stars.
+    pub spacing: f32,
+    /// Stars stop spawning over time while this many are on the field.
+    pub max_live: usize,
+    /// Seconds a star stays on the field before it expires. 0 keeps stars until they are collected.
+    pub lifetime: f32,
+    /// Stars blink and fade out over the last `fade_time` seconds of their lifetime.
+    pub fade_time: f32,
+    /// Extra points for a star collected the moment it appears, shrinking to none as it expires.
+    pub max_bonus: u32,
+    /// Collectibles that can appear, each picked with a chance proportional to its `weight`.
+    pub kinds: Vec<StarKind>
+}
+
+impl Default for StarConfig {
+    fn default() -> StarConfig {
+        StarConfig {
+            size: 30.0,
+            count: 10,
+            spawn_time: 1.0,
+            spacing: 80.0,
+            max_live: 25,
+            lifetime: 8.0,
+            fade_time: 2.0,
+            max_bonus: 0,
+            kinds: vec![StarKind::default()]
+        }
+    }
+}
+
+impl StarConfig {
+    pub fn pick_kind(&self, rng: &mut impl Rng) -> Option<&StarKind> {
+        pick_weighted(&self.kinds, |kind| kind.weight, rng)
+    }
+}
+
+/// A collectible the player can pick up, e.g. a gold star worth more than a bronze one.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct StarKind {
+    pub name: String,
+    pub sprite: String,
+    pub sound: String,
+    /// Multiplied into the sprite's colors, each channel in `0..1`.
+    pub tint: (f32, f32, f32),
+    pub weight: u32,
+    pub score: u32
+}
+
+impl Default for StarKind {
+    fn default() -> StarKind {
+        StarKind {
+            name: "Star".to_string(),
+            sprite: "sprites/star.png".to_string(),
+            sound: "audio/laserLarge_000.ogg".to_string(),
+            tint: (1.0, 1.0, 1.0),
+            weight: 1,
+            score: 1
+        }
+    }
+}
+
+impl StarKind {
+    pub fn color(&self) -> Color {
+        Color::rgb(self.tint.0, self.tint.1, self.tint.2)
+    }
+}
+
+/// Pickups granting the player a timed effect. Speed and slow-down factors multiply the usual speeds.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PowerUpConfig {
+    pub size: f32,
+    pub spawn_time: f32,
+    /// Seconds a pickup stays on the field before it disappears.
+    pub lifetime: f32,
+    /// Seconds an effect lasts once picked up. A shield also ends when it absorbs a hit.
+    pub duration: f32,
+    pub speed_boost: f32,
+    pub slow_motion: f32,
+    pub magnet_radius: f32,
+    pub magnet_speed: f32
+}
+
+impl Default for PowerUpConfig {
+    fn default() -> PowerUpConfig {
+        PowerUpConfig {
+            size: 40.0,
+            spawn_time: 12.0,
+            lifetime: 6.0,
+            duration: 6.0,
+            speed_boost: 1.6,
+            slow_motion: 0.5,
+            magnet_radius: 300.0,
+            magnet_speed: 400.0
+        }
+    }
+}
+
+impl GameConfig {
+    pub fn path() -> PathBuf {
+        FileAssetReader::get_base_path().join("assets").join(GAME_CONFIG_PATH)
+    }
+
+    pub fn load(path: &Path) -> Result<GameConfig, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|error| format!("could not read {}: {}", path.display(), error))?;
+        let game_config: GameConfig = ron::from_str(&contents)
+            .map_err(|error| format!("could not parse {}: {}", path.display(), error))?;
+
+        game_config.validate()
+            .map_err(|error| format!("invalid values in {}: {}", path.display(), error))?;
+        Ok(game_config)
+    }
+
+    pub fn with_difficulty(&self, difficulty: Difficulty) -> GameConfig {
+        let scaling = difficulty.scaling(self);
+        let mut game_config = self.clone();
+
+        let enemy_count = (self.enemy.count as f32 * scaling.enemy_count).round() as usize;
+        game_config.enemy.count = enemy_count.min(MAX_SPAWNED_ENTITIES);
+        game_config.enemy.speed *= scaling.enemy_speed;
+        game_config.enemy.spawn_time *= scaling.enemy_spawn_time;
+        game_config.star.spawn_time *= scaling.star_spawn_time;
+        game_config
+    }
+
+    /// Checks every value and reports all problems at once, e.g. `enemy.speed must be at least 0, got -5`.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut problems = Vec::new();
+
+        check_positive(&mut problems, "player.size", self.player.size);
+        check_not_negative(&mut problems, "player.speed", self.player.speed);
+        if self.player.lives == 0 {
+            problems.push("player.lives must be at least 1".to_string());
+        }
+        check_not_negative(&mut problems, "player.invulnerable_time", self.player.invulnerable_time);
+        check_positive(&mut problems, "enemy.size", self.enemy.size);
+        check_not_negative(&mut problems, "enemy.speed", self.enemy.speed);
+        check_count(&mut problems, "enemy.count", self.enemy.count);
+        check_positive(&mut problems, "enemy.spawn_time", self.enemy.spawn_time);
+        check_not_negative(&mut problems, "enemy.safe_spawn_radius", self.enemy.safe_spawn_radius);
+        if self.enemy.kinds.iter().all(|(_, weight)| *weight == 0) {
+            problems.push("enemy.kinds needs at least one kind with a weight above 0".to_string());
+        }
+        check_positive(&mut problems, "enemy.chaser.size", self.enemy.chaser.size);
+        check_not_negative(&mut problems, "enemy.chaser.speed", self.enemy.chaser.speed);
+        check_not_negative(&mut problems, "enemy.chaser.turn_rate", self.enemy.chaser.turn_rate);
+        check_positive(&mut problems, "enemy.zig_zag.size", self.enemy.zig_zag.size);
+        check_not_negative(&mut problems, "enemy.zig_zag.speed", self.enemy.zig_zag.speed);
+        check_positive(&mut problems, "enemy.zig_zag.interval", self.enemy.zig_zag.interval);
+        check_not_negative(&mut problems, "enemy.zig_zag.swing", self.enemy.zig_zag.swing);
+        check_positive(&mut problems, "enemy.tank.size", self.enemy.tank.size);
+        check_not_negative(&mut problems, "enemy.tank.speed", self.enemy.tank.speed);
+        check_positive(&mut problems, "enemy.tank.mass", self.enemy.tank.mass);
+        check_positive(&mut problems, "enemy.dart.size", self.enemy.dart.size);
+        check_not_negative(&mut problems, "enemy.dart.speed", self.enemy.dart.speed);
+        check_positive(&mut problems, "star.size", self.star.size);
+        check_count(&mut problems, "star.count", self.star.count);
+        check_positive(&mut problems, "star.spawn_time", self.star.spawn_time);
+        check_not_negative(&mut problems, "star.spacing", self.star.spacing);
+        check_count(&mut problems, "star.max_live", self.star.max_live);
+        check_not_negative(&mut problems, "star.lifetime", self.star.lifetime);
+        check_not_negative(&mut problems, "star.fade_time", self.star.fade_time);
+        if self.star.kinds.iter().all(|kind| kind.weight == 0) {
+            problems.push("star.kinds needs at least one kind with a weight above 0".to_string());
+        }
+        for (index, kind) in self.star.kinds.iter().enumerate() {
+            if kind.sprite.is_empty() {
+                problems.push(format!("star.kinds[{}].sprite must not be empty", index));
+            }
+        }
+        check_positive(&mut problems, "power_up.size", self.power_up.size);
+        check_positive(&mut problems, "power_up.spawn_time", self.power_up.spawn_time);
+        check_positive(&mut problems, "power_up.lifetime", self.power_up.lifetime);
+        check_positive(&mut problems, "power_up.duration", self.power_up.duration);
+        check_not_negative(&mut problems, "power_up.speed_boost", self.power_up.speed_boost);
+        check_not_negative(&mut problems, "power_up.slow_motion", self.power_up.slow_motion);
+        check_not_negative(&mut problems, "power_up.magnet_radius", self.power_up.magnet_radius);
+        check_not_negative(&mut problems, "power_up.magnet_speed", self.power_up.magnet_speed);
+        check_positive(&mut problems, "director.seconds_per_level", self.director.seconds_per_level);
+        check_step(&mut problems, "director.enemy_speed_step", self.director.enemy_speed_step);
+        check_step(&mut problems, "director.enemy_spawn_time_step", self.director.enemy_spawn_time_step);
+        check_step(&mut problems, "director.star_spawn_time_step", self.director.star_spawn_time_step);
+        check_not_negative(&mut problems, "custom_difficulty.enemy_count", self.custom_difficulty.enemy_count);
+        check_not_negative(&mut problems, "custom_difficulty.enemy_speed", self.custom_difficulty.enemy_speed);
+        check_positive(&mut problems, "custom_difficulty.enemy_spawn_time", self.custom_difficulty.enemy_spawn_time);
+        check_positive(&mut problems, "custom_difficulty.star_spawn_time", self.custom_difficulty.star_spawn_time);
+
+        if problems.is_empty() { Ok(()) } else { Err(problems.join("; ")) }
+    }
+}
+
+/// Remembers when the config file was last changed so edits can be picked up while the game runs.
+#[derive(Resource)]
+pub struct GameConfigWatcher {
+    pub timer: Timer,
+    pub modified: Option<SystemTime>
+}
+
+impl Default for GameConfigWatcher {
+    fn default() -> GameConfigWatcher {
+        GameConfigWatcher {
+            timer: Timer::from_seconds(GAME_CONFIG_POLL_INTERVAL, TimerMode::Repeating),
+            modified: None
+        }
+    }
+}
+
+impl GameConfigWatcher {
+    pub fn modified_time(path: &Path) -> Option<SystemTime> {
+        fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+    }
+}
+
+fn check_positive(problems: &mut Vec<String>, name: &str, value: f32) {
+    if !value.is_finite() || value <= 0.0 {
+        problems.push(format!("{} must be greater than 0, got {}", name, value));
+    }
+}
+
+fn check_not_negative(problems: &mut Vec<String>, name: &str, value: f32) {
+    if !value.is_finite() || value < 0.0 {
+        problems.push(format!("{} must be at least 0, got {}", name, value));
+    }
+}
+
+fn check_step(problems: &mut Vec<String>, name: &str, value: f32) {
+    if !value.is_finite() || value <= -1.0 {
+        problems.push(format!("{} must be greater than -1, got {}", name, value));
+    }
+}
+
+fn check_count(problems: &mut Vec<String>, name: &str, value: usize) {
+    if value > MAX_SPAWNED_ENTITIES {
+        problems.push(format!("{} must be at most {}, got {}", name, MAX_SPAWNED_ENTITIES, value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn defaults_are_valid() {
+        assert_eq!(GameConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn shipped_config_loads() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets").join(GAME_CONFIG_PATH);
+        assert!(GameConfig::load(&path).is_ok());
+    }
+
+    #[test]
+    fn reports_every_problem_at_once() {
+        let mut game_config = GameConfig::default();
+        game_config.player.size = 0.0;
+        game_config.enemy.speed = -5.0;
+        game_config.star.count = MAX_SPAWNED_ENTITIES + 1;
+
+        let error = game_config.validate().unwrap_err();
+        assert!(error.contains("player.size must be greater than 0, got 0"));
+        assert!(error.contains("enemy.speed must be at least 0, got -5"));
+        assert!(error.contains(&format!("star.count must be at most {}", MAX_SPAWNED_ENTITIES)));
+        assert_eq!(error.split("; ").count(), 3);
+    }
+
+    #[test]
+    fn rejects_values_that_are_not_finite() {
+        let mut game_config = GameConfig::default();
+        game_config.power_up.magnet_radius = f32::NAN;
+        game_config.director.enemy_speed_step = f32::INFINITY;
+
+        let error = game_config.validate().unwrap_err();
+        assert!(error.contains("power_up.magnet_radius"));
+        assert!(error.contains("director.enemy_speed_step"));
+    }
+
+    #[test]
+    fn needs_a_life_and_a_weighted_kind() {
+        let mut game_config = GameConfig::default();
+        game_config.player.lives = 0;
+        game_config.enemy.kinds = vec![(EnemyKind::Ball, 0), (EnemyKind::Tank, 0)];
+        game_config.star.kinds = Vec::new();
+
+        let error = game_config.validate().unwrap_err();
+        assert!(error.contains("player.lives must be at least 1"));
+        assert!(error.contains("enemy.kinds needs at least one kind"));
+        assert!(error.contains("star.kinds needs at least one kind"));
+    }
+
+    #[test]
+    fn director_steps_must_stay_above_minus_one() {
+        let mut game_config = GameConfig::default();
+        game_config.director.star_spawn_time_step = -1.0;
+        assert!(game_config.validate().is_err());
+
+        game_config.director.star_spawn_time_step = -0.5;
+        assert_eq!(game_config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn director_level_steps_with_time_and_stars() {
+        let director = DirectorConfig::default();
+        assert_eq!(director.level(0.0, 0), 0);
+        assert_eq!(director.level(19.9, 0), 0);
+        assert_eq!(director.level(20.0, 0), 1);
+        assert_eq!(director.level(0.0, 4), 0);
+        assert_eq!(director.level(0.0, 5), 1);
+        assert_eq!(director.level(45.0, 12), 4);
+    }
+
+    #[test]
+    fn director_level_is_capped() {
+        let director = DirectorConfig { max_level: 3, ..default() };
+        assert_eq!(director.level(1000.0, 0), 3);
+        assert_eq!(director.level(0.0, 1000), 3);
+    }
+
+    #[test]
+    fn zero_stars_per_level_ignores_stars() {
+        let director = DirectorConfig { stars_per_level: 0, ..default() };
+        assert_eq!(director.level(0.0, 50), 0);
+        assert_eq!(director.level(40.0, 50), 2);
+    }
+
+    #[test]
+    fn director_factor_follows_the_curve() {
+        let linear = DirectorConfig { curve: DifficultyCurve::Linear, ..default() };
+        assert_eq!(linear.factor(0, 0.5), 1.0);
+        assert_eq!(linear.factor(3, 0.5), 2.5);
+        assert_eq!(linear.factor(20, -0.1), MIN_DIRECTOR_FACTOR);
+
+        let exponential = DirectorConfig { curve: DifficultyCurve::Exponential, ..default() };
+        assert_eq!(exponential.factor(0, 0.5), 1.0);
+        assert_eq!(exponential.factor(3, 0.5), 3.375);
+        assert!(exponential.factor(20, -0.1) > 0.0);
+    }
+}
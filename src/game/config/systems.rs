@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+
+use super::resources::{BaseGameConfig, Difficulty, GameConfig, GameConfigWatcher};
+use crate::events::GameConfigReloaded;
+
+pub fn load_game_config(
+    mut base_game_config: ResMut<BaseGameConfig>,
+    mut watcher: ResMut<GameConfigWatcher>
+) {
+    let path = GameConfig::path();
+    watcher.modified = GameConfigWatcher::modified_time(&path);
+
+    if !path.exists() {
+        println!("No game config at {}, using defaults", path.display());
+        return;
+    }
+
+    match GameConfig::load(&path) {
+        Ok(game_config) => {
+            println!("Loaded game config from {}", path.display());
+            base_game_config.config = game_config;
+        }
+        Err(error) => println!("Ignoring game config: {}; using defaults", error)
+    }
+}
+
+/// Polls the config file and swaps in the new values once it has been edited. A file that no longer
+/// parses or validates is reported and the current config is kept.
+pub fn reload_game_config(
+    mut watcher: ResMut<GameConfigWatcher>,
+    mut base_game_config: ResMut<BaseGameConfig>,
+    mut reloaded_event_writer: EventWriter<GameConfigReloaded>,
+    time: Res<Time<Real>>
+) {
+    if !watcher.timer.tick(time.delta()).just_finished() { return; }
+
+    let path = GameConfig::path();
+    let modified = GameConfigWatcher::modified_time(&path);
+    if modified.is_none() || modified == watcher.modified { return; }
+    watcher.modified = modified;
+
+    match GameConfig::load(&path) {
+        Ok(new_config) if new_config == base_game_config.config => {}
+        Ok(new_config) => {
+            base_game_config.config = new_config;
+            println!("Reloaded game config from {}", path.display());
+            reloaded_event_writer.send(GameConfigReloaded {});
+        }
+        Err(error) => println!("Keeping current game config: {}", error)
+    }
+}
+
+pub fn apply_difficulty(
+    base_game_config: Res<BaseGameConfig>,
+    difficulty: Res<Difficulty>,
+    mut game_config: ResMut<GameConfig>
+) {
+    *game_config = base_game_config.config.with_difficulty(*difficulty);
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use systems::*;
+
+use crate::AppState;
+
+use super::{RngSystemSet, SimulationState};
+use super::config::ConfigSystemSet;
+use super::player::PlayerSystemSet;
+
+/// Raises the difficulty level as a run goes on; enemies and stars read their current speed and
+/// spawn intervals through `DifficultyDirector`.
+pub struct DirectorPlugin;
+
+impl Plugin for DirectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Game), insert_director.after(ConfigSystemSet))
+            .add_systems(FixedUpdate, update_director
+                .after(PlayerSystemSet)
+                .before(RngSystemSet::Enemy)
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::Running)))
+            .add_systems(OnExit(AppState::Game), remove_director);
+    }
+}
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use crate::game::config::resources::GameConfig;
+
+/// Difficulty level of the current run, driven by simulated time survived and stars collected.
+#[derive(Resource, Debug, Default)]
+pub struct DifficultyDirector {
+    pub elapsed: f32,
+    pub stars_collected: u32,
+    pub level: u32
+}
+
+impl DifficultyDirector {
+    pub fn enemy_speed(&self, base_speed: f32, game_config: &GameConfig) -> f32 {
+        base_speed * game_config.director.factor(self.level, game_config.director.enemy_speed_step)
+    }
+
+    pub fn enemy_spawn_time(&self, game_config: &GameConfig) -> f32 {
+        game_config.enemy.spawn_time * game_config.director.factor(self.level, game_config.director.enemy_spawn_time_step)
+    }
+
+    pub fn star_spawn_time(&self, game_config: &GameConfig) -> f32 {
+        game_config.star.spawn_time * game_config.director.factor(self.level, game_config.director.star_spawn_time_step)
+    }
+}
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use super::resources::DifficultyDirector;
+use crate::events::StarCollected;
+use crate::game::config::resources::GameConfig;
+
+pub fn insert_director(
+    mut commands: Commands
+) {
+    commands.insert_resource(DifficultyDirector::default());
+}
+
+pub fn remove_director(
+    mut commands: Commands
+) {
+    commands.remove_resource::<DifficultyDirector>();
+}
+
+pub fn update_director(
+    mut director: ResMut<DifficultyDirector>,
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    game_config: Res<GameConfig>,
+    time: Res<Time>
+) {
+    director.elapsed += time.delta_seconds();
+    director.stars_collected += star_collected_event_reader.read().count() as u32;
+
+    let level = game_config.director.level(director.elapsed, director.stars_collected);
+    if level != director.level {
+        director.level = level;
+        println!("Difficulty level {}", level);
+    }
+}
@@ -1,6 +1,74 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Archetypes have their own size and speed in `EnemyConfig` and a behaviour component: Chaser steers
+/// toward the player, ZigZag swerves from side to side, Tank is heavy in enemy collisions and Dart aims
+/// at the player off every wall. Ball just bounces in straight lines.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnemyKind {
+    #[default]
+    Ball,
+    Chaser,
+    ZigZag,
+    Tank,
+    Dart
+}
+
+impl EnemyKind {
+    pub fn color(self) -> Color {
+        match self {
+            EnemyKind::Ball => Color::WHITE,
+            EnemyKind::Chaser => Color::rgb(1.0, 0.5, 1.0),
+            EnemyKind::ZigZag => Color::rgb(0.6, 1.0, 0.5),
+            EnemyKind::Tank => Color::rgb(0.6, 0.3, 0.3),
+            EnemyKind::Dart => Color::rgb(1.0, 0.9, 0.4)
+        }
+    }
+}
 
 #[derive(Component)]
 pub struct Enemy {
-    pub direction: Vec2
-}
\ No newline at end of file
+    pub kind: EnemyKind,
+    pub direction: Vec2,
+    pub speed: f32,
+    pub size: f32
+}
+
+impl Enemy {
+    /// Balls are flat discs, so mass grows with area.
+    pub fn mass(&self) -> f32 {
+        self.size * self.size
+    }
+
+    pub fn velocity(&self) -> Vec2 {
+        self.direction * self.speed
+    }
+
+    pub fn set_velocity(&mut self, velocity: Vec2) {
+        self.speed = velocity.length();
+        if self.speed > 0.0 { self.direction = velocity / self.speed; }
+    }
+}
+
+/// Turns an enemy's heading toward the player by at most `turn_rate` radians per second.
+#[derive(Component)]
+pub struct Homing {
+    pub turn_rate: f32
+}
+
+/// Swings an enemy's heading by `swing` radians every time `timer` finishes, alternating sides.
+#[derive(Component)]
+pub struct ZigZag {
+    pub timer: Timer,
+    pub swing: f32
+}
+
+/// Multiplies an enemy's mass in enemy collisions, so others glance off it.
+#[derive(Component)]
+pub struct Heavy {
+    pub mass_scale: f32
+}
+
+/// Aims an enemy straight at the player every time it bounces off a wall.
+#[derive(Component)]
+pub struct Lunging {}
@@ -4,28 +4,36 @@ pub mod components;
 pub mod resources;
 mod systems;
 
-use resources::EnemySpawnTimer;
+use resources::AppliedEnemyConfig;
 use systems::*;
 
 use crate::AppState;
 
-use super::SimulationState;
+use super::{RngSystemSet, SimulationState};
+use super::config::{ConfigSystemSet, resources::GameConfig};
+use super::level::resources::{GameMode, LevelProgress};
 
-pub const ENEMY_SIZE: f32 = 64.0;
-pub const ENEMY_SPEED: f32 = 200.0;
-pub const NUMBER_OF_ENEMIES: usize = 6;
+/// Sprite every enemy is drawn with, tinted by its `EnemyKind`.
+pub const ENEMY_SPRITE: &str = "sprites/ball_red_large.png";
 
 pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<EnemySpawnTimer>()
-            .add_systems(OnEnter(AppState::Game), spawn_enemies)
-            .add_systems(Update, (enemy_movement, confine_enemy_movement, update_enemy_direction, 
-                tick_enemy_spawn_timer, spawn_enemies_over_time)
+        app.add_systems(OnEnter(AppState::Game), ((insert_enemy_spawn_timer, insert_applied_enemy_config).after(ConfigSystemSet), spawn_enemies.in_set(RngSystemSet::Enemy).run_if(resource_equals(GameMode::Endless))))
+            .add_systems(FixedUpdate, (steer_homing_enemies, zig_zag_enemies, enemy_movement, collide_enemies, confine_enemy_movement, update_enemy_direction, 
+                tick_enemy_spawn_timer, spawn_enemies_over_time.run_if(resource_equals(GameMode::Endless)),
+                spawn_waves.run_if(resource_exists::<LevelProgress>()))
+                    .chain()
+                    .in_set(RngSystemSet::Enemy)
                     .run_if(in_state(AppState::Game))
                     .run_if(in_state(SimulationState::Running))
                 )
-            .add_systems(OnExit(AppState::Game), despawn_enemies);
+            .add_systems(PreUpdate, apply_enemy_config
+                .after(ConfigSystemSet)
+                .run_if(in_state(AppState::Game))
+                .run_if(resource_exists::<AppliedEnemyConfig>())
+                .run_if(resource_changed::<GameConfig>()))
+            .add_systems(OnExit(AppState::Game), (despawn_enemies, remove_enemy_spawn_timer, remove_applied_enemy_config));
     }
 }
\ No newline at end of file
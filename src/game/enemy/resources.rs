@@ -1,14 +1,20 @@
 use bevy::prelude::*;
 
-pub const ENEMY_SPAWN_TIME: f32 = 3.0;
+use crate::game::config::resources::EnemyConfig;
 
 #[derive(Resource)]
 pub struct EnemySpawnTimer {
     pub timer: Timer
 }
 
-impl Default for EnemySpawnTimer {
-    fn default() -> EnemySpawnTimer {
-        EnemySpawnTimer { timer: Timer::from_seconds(ENEMY_SPAWN_TIME, TimerMode::Repeating) }
+impl EnemySpawnTimer {
+    pub fn from_seconds(seconds: f32) -> EnemySpawnTimer {
+        EnemySpawnTimer { timer: Timer::from_seconds(seconds, TimerMode::Repeating) }
     }
 }
+
+/// Enemy config the enemies on the field were set up with, so a config edit can tell which speeds changed.
+#[derive(Resource)]
+pub struct AppliedEnemyConfig {
+    pub enemy: EnemyConfig
+}
@@ -1,34 +1,92 @@
-use bevy::{prelude::*, window::PrimaryWindow, audio::VolumeLevel};
-use rand::random;
+use std::time::Duration;
 
-use crate::game::helpers::helpers::confine_movement;
+use bevy::{prelude::*, audio::VolumeLevel, ecs::system::SystemParam, utils::HashMap};
+use rand::Rng;
 
+use crate::game::collision::components::Collider;
+use crate::game::collision::resources::{CollisionLayer, SpatialHash};
+use crate::game::config::resources::GameConfig;
+use crate::game::director::resources::DifficultyDirector;
+use crate::game::helpers::helpers::{clearance_from, confine_movement, pick_spawn_point};
+use crate::game::interpolation::components::Interpolated;
+use crate::game::level::resources::{LevelProgress, Levels, SpawnPattern};
+use crate::game::player::components::Player;
+use crate::game::power_up::components::{ActivePowerUps, PowerUpKind};
+use crate::game::resources::{GameRng, Playfield};
+
+use super::ENEMY_SPRITE;
 use super::components::*;
-use super::resources::EnemySpawnTimer;
-use super::{NUMBER_OF_ENEMIES, ENEMY_SIZE, ENEMY_SPEED};
+use super::resources::{AppliedEnemyConfig, EnemySpawnTimer};
 
-pub fn spawn_enemies(
-    mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>
-) {
-    let window = window_query.get_single().unwrap();
+/// Everything needed to place and spawn an enemy away from the player.
+#[derive(SystemParam)]
+pub struct EnemySpawner<'w, 's> {
+    commands: Commands<'w, 's>,
+    asset_server: Res<'w, AssetServer>,
+    game_config: Res<'w, GameConfig>,
+    playfield: Res<'w, Playfield>,
+    game_rng: ResMut<'w, GameRng>,
+    player_query: Query<'w, 's, &'static Transform, With<Player>>
+}
+
+impl EnemySpawner<'_, '_> {
+    /// Spawns the `index`th of `count` enemies at the pattern's position, or at a random spot fully inside
+    /// the arena if that position is within `safe_spawn_radius` of the player.
+    fn spawn(&mut self, kind: EnemyKind, pattern: SpawnPattern, index: usize, count: usize) {
+        let player_position = self.player_query.get_single()
+            .map_or(self.playfield.center(), |transform| transform.translation.truncate());
+        let enemy_config = &self.game_config.enemy;
+        let safe_spawn_radius = enemy_config.safe_spawn_radius;
 
-    for _ in 0..NUMBER_OF_ENEMIES {
-        let (random_x, random_y) = (random::<f32>() * window.width(), random::<f32>() * window.height());
+        let size = enemy_config.kind_size(kind);
+        let playfield = &self.playfield;
+        let mut attempt = 0;
+        let clearance = |candidate| clearance_from(candidate, [player_position], safe_spawn_radius);
+        let position = pick_spawn_point(&mut self.game_rng, clearance, |game_rng| {
+            attempt += 1;
+            let pattern = if attempt == 1 { pattern } else { SpawnPattern::Random };
+            pattern.position(index, count, playfield, size, game_rng)
+        });
+        let direction = self.game_rng.random_fraction().normalize();
 
-        commands.spawn(
+        let mut enemy = self.commands.spawn(
             (
                 SpriteBundle {
-                    transform: Transform::from_xyz(random_x, random_y, 0.0),
-                    texture: asset_server.load("sprites/ball_red_large.png"),
+                    transform: Transform::from_translation(position.extend(0.0)),
+                    texture: self.asset_server.load(ENEMY_SPRITE),
+                    sprite: Sprite { color: kind.color(), custom_size: Some(Vec2::splat(size)), ..default() },
                     ..default()
                 },
                 Enemy {
-                    direction: Vec2::new(random::<f32>(), random::<f32>()).normalize()
-                }
+                    kind,
+                    direction,
+                    speed: enemy_config.kind_speed(kind),
+                    size
+                },
+                Collider::circle(size / 2.0, CollisionLayer::Enemy),
+                Interpolated::new(position.extend(0.0))
             )
         );
+        match kind {
+            EnemyKind::Ball => {}
+            EnemyKind::Chaser => { enemy.insert(Homing { turn_rate: enemy_config.chaser.turn_rate }); }
+            EnemyKind::ZigZag => {
+                let zig_zag = &enemy_config.zig_zag;
+                enemy.insert(ZigZag { timer: Timer::from_seconds(zig_zag.interval, TimerMode::Repeating), swing: zig_zag.swing });
+            }
+            EnemyKind::Tank => { enemy.insert(Heavy { mass_scale: enemy_config.tank.mass }); }
+            EnemyKind::Dart => { enemy.insert(Lunging {}); }
+        }
+    }
+}
+
+pub fn spawn_enemies(
+    mut spawner: EnemySpawner
+) {
+    let count = spawner.game_config.enemy.count;
+    for index in 0..count {
+        let kind = spawner.game_config.enemy.pick_kind(&mut spawner.game_rng.rng);
+        spawner.spawn(kind, SpawnPattern::Random, index, count);
     }
 }
 
@@ -43,30 +101,82 @@ pub fn despawn_enemies(
 
 pub fn enemy_movement(
     mut enemy_query: Query<(&mut Transform, &Enemy)>,
+    power_up_query: Query<&ActivePowerUps>,
+    director: Res<DifficultyDirector>,
+    game_config: Res<GameConfig>,
     time: Res<Time>
 ) {
+    let slow_motion = power_up_query.get_single()
+        .map_or(1.0, |active_power_ups| active_power_ups.factor(PowerUpKind::SlowMotion, game_config.power_up.slow_motion));
+
     for (mut transform, enemy) in enemy_query.iter_mut() {
         let direction = Vec3::new(enemy.direction.x, enemy.direction.y, 0.0);
-        transform.translation += direction * ENEMY_SPEED * time.delta_seconds()
+        transform.translation += direction * director.enemy_speed(enemy.speed, &game_config) * slow_motion * time.delta_seconds()
     } 
 }
 
-pub fn confine_enemy_movement(
-    mut enemy_query: Query<&mut Transform>,
-    window_query: Query<&Window, With<PrimaryWindow>>
+/// Separates overlapping enemies and exchanges momentum between them in a perfectly elastic collision.
+/// The spatial hash still holds positions from before this step's movement, so each enemy looks a full
+/// diameter around itself, which covers the distance moved since.
+pub fn collide_enemies(
+    mut enemy_query: Query<(Entity, &mut Transform, &mut Enemy, Option<&Heavy>)>,
+    spatial_hash: Res<SpatialHash>,
+    game_config: Res<GameConfig>
 ) {
-    let window = window_query.get_single().unwrap();
+    if !game_config.enemy.collisions { return; }
+
+    // Pairs are handled in query order rather than by entity id, which differs between a run and its replay.
+    let enemies: Vec<(Entity, Vec2, f32)> = enemy_query.iter()
+        .map(|(entity, transform, enemy, _)| (entity, transform.translation.truncate(), enemy.size))
+        .collect();
+    let order: HashMap<Entity, usize> = enemies.iter().enumerate().map(|(index, (entity, _, _))| (*entity, index)).collect();
+
+    for (index, (entity, position, size)) in enemies.iter().enumerate() {
+        for other in spatial_hash.query(*position, *size, CollisionLayer::Enemy) {
+            if order.get(&other.entity).map_or(true, |other_index| *other_index <= index) { continue; }
+            let entity = *entity;
+            let Ok([(_, mut transform_a, mut enemy_a, heavy_a), (_, mut transform_b, mut enemy_b, heavy_b)]) = enemy_query.get_many_mut([entity, other.entity]) else { continue; };
+
+            let offset = (transform_b.translation - transform_a.translation).truncate();
+            let distance = offset.length();
+            let min_distance = (enemy_a.size + enemy_b.size) / 2.0;
+            if distance >= min_distance || distance == 0.0 { continue; }
+
+            let normal = offset / distance;
+            let mass_scale = |heavy: Option<&Heavy>| heavy.map_or(1.0, |heavy| heavy.mass_scale);
+            let (mass_a, mass_b) = (enemy_a.mass() * mass_scale(heavy_a), enemy_b.mass() * mass_scale(heavy_b));
+            let total_mass = mass_a + mass_b;
+
+            let overlap = min_distance - distance;
+            transform_a.translation -= (normal * overlap * mass_b / total_mass).extend(0.0);
+            transform_b.translation += (normal * overlap * mass_a / total_mass).extend(0.0);
 
-    for transform in enemy_query.iter_mut() {
-        confine_movement(transform, window, ENEMY_SIZE);
+            let (velocity_a, velocity_b) = (enemy_a.velocity(), enemy_b.velocity());
+            let closing_speed = (velocity_a - velocity_b).dot(normal);
+            if closing_speed <= 0.0 { continue; }
+
+            let impulse = 2.0 * closing_speed / total_mass;
+            enemy_a.set_velocity(velocity_a - normal * impulse * mass_b);
+            enemy_b.set_velocity(velocity_b + normal * impulse * mass_a);
+        }
+    }
+}
+
+pub fn confine_enemy_movement(
+    mut enemy_query: Query<(&mut Transform, &Enemy)>,
+    playfield: Res<Playfield>
+) {
+    for (transform, enemy) in enemy_query.iter_mut() {
+        confine_movement(transform, &playfield, enemy.size);
     }
 }
 
 fn make_bump_sound(
     mut commands: Commands,
-    asset_server: Res<AssetServer>
+    asset_server: Res<AssetServer>,
+    game_rng: &mut GameRng
 ) {
-    let effect_path = if random::<f32>() > 0.5 { "audio/pluck_001.ogg" } else { "audio/pluck_002.ogg" };
+    let effect_path = if game_rng.rng.gen::<f32>() > 0.5 { "audio/pluck_001.ogg" } else { "audio/pluck_002.ogg" };
 
     commands.spawn(
         AudioBundle {
@@ -80,59 +190,255 @@ fn make_bump_sound(
     );
 }
 
+/// Bounces enemies off the walls. Lunging enemies head straight for the player instead.
 pub fn update_enemy_direction(
     commands: Commands,
-    mut enemy_query: Query<(&Transform, &mut Enemy)>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>
+    mut enemy_query: Query<(&Transform, &mut Enemy, Option<&Lunging>)>,
+    player_query: Query<&Transform, With<Player>>,
+    playfield: Res<Playfield>,
+    asset_server: Res<AssetServer>,
+    mut game_rng: ResMut<GameRng>
 ) {
-    let window = window_query.get_single().unwrap();
-    let half_size = ENEMY_SIZE / 2.0;
     let mut is_updated = false;
+    let player_position = player_query.get_single().ok().map(|transform| transform.translation.truncate());
 
-    for (transform, mut enemy) in enemy_query.iter_mut() {
+    for (transform, mut enemy, lunging) in enemy_query.iter_mut() {
+        let (min, max) = playfield.inner_bounds(enemy.size);
         let translation = transform.translation;
-        if translation.x <= half_size || translation.x >= window.width() - half_size {
+        let mut is_bounced = false;
+        if translation.x <= min.x || translation.x >= max.x {
             enemy.direction.x *= -1.0;
-            is_updated = true;
+            is_bounced = true;
         }
-        if translation.y <= half_size || translation.y >= window.height() - half_size {
+        if translation.y <= min.y || translation.y >= max.y {
             enemy.direction.y *= -1.0;
-            is_updated = true;
+            is_bounced = true;
+        }
+        if !is_bounced { continue; }
+        is_updated = true;
+
+        if let (Some(_), Some(player_position)) = (lunging, player_position) {
+            let target = player_position - translation.truncate();
+            if target != Vec2::ZERO { enemy.direction = target.normalize(); }
         }
     }
 
-    if is_updated { make_bump_sound(commands, asset_server); }
+    if is_updated { make_bump_sound(commands, asset_server, &mut game_rng); }
+}
+
+pub fn insert_enemy_spawn_timer(
+    mut commands: Commands,
+    game_config: Res<GameConfig>
+) {
+    commands.insert_resource(EnemySpawnTimer::from_seconds(game_config.enemy.spawn_time));
+}
+
+pub fn remove_enemy_spawn_timer(
+    mut commands: Commands
+) {
+    commands.remove_resource::<EnemySpawnTimer>();
+}
+
+pub fn insert_applied_enemy_config(
+    mut commands: Commands,
+    game_config: Res<GameConfig>
+) {
+    commands.insert_resource(AppliedEnemyConfig { enemy: game_config.enemy.clone() });
+}
+
+pub fn remove_applied_enemy_config(
+    mut commands: Commands
+) {
+    commands.remove_resource::<AppliedEnemyConfig>();
 }
 
 pub fn tick_enemy_spawn_timer(
     mut enemy_spawn_timer: ResMut<EnemySpawnTimer>,
+    director: Res<DifficultyDirector>,
+    game_config: Res<GameConfig>,
     time: Res<Time>
 ) {
+    enemy_spawn_timer.timer.set_duration(Duration::from_secs_f32(director.enemy_spawn_time(&game_config)));
     enemy_spawn_timer.timer.tick(time.delta());
 }
 
 pub fn spawn_enemies_over_time(
-    mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    mut spawner: EnemySpawner,
     enemy_spawn_timer: Res<EnemySpawnTimer>
 ) {
     if enemy_spawn_timer.timer.finished() {
-        let window = window_query.get_single().unwrap();
-        let (random_x, random_y) = (random::<f32>() * window.width(), random::<f32>() * window.height());
+        let kind = spawner.game_config.enemy.pick_kind(&mut spawner.game_rng.rng);
+        spawner.spawn(kind, SpawnPattern::Random, 0, 1);
+    }
+}
 
-        commands.spawn(
-            (
-                SpriteBundle {
-                    transform: Transform::from_xyz(random_x, random_y, 0.0),
-                    texture: asset_server.load("sprites/ball_red_large.png"),
-                    ..default()
-                },
-                Enemy {
-                    direction: Vec2::new(random::<f32>(), random::<f32>()).normalize()
-                }
-            )
-        );
+/// Spawns the level's next wave once its delay has run out, then starts the delay of the one after.
+pub fn spawn_waves(
+    mut spawner: EnemySpawner,
+    levels: Res<Levels>,
+    mut progress: ResMut<LevelProgress>
+) {
+    if !progress.wave_timer.finished() { return; }
+    let Some(level) = levels.levels.get(progress.level) else { return; };
+    let Some(wave) = level.waves.get(progress.next_wave) else { return; };
+
+    for index in 0..wave.count {
+        spawner.spawn(wave.enemy, wave.pattern, index, wave.count);
+    }
+
+    progress.next_wave += 1;
+    if let Some(next_wave) = level.waves.get(progress.next_wave) {
+        progress.wave_timer = Timer::from_seconds(next_wave.delay, TimerMode::Once);
+    }
+}
+
+/// Brings enemies already on the field in line with an edited config, archetype behaviours included.
+/// An enemy's speed is only touched when its archetype's configured speed changed, and then rescaled,
+/// so it keeps its own speed relative to the rest.
+pub fn apply_enemy_config(
+    mut enemy_query: Query<(&mut Enemy, &mut Sprite, &mut Collider)>,
+    mut homing_query: Query<&mut Homing>,
+    mut zig_zag_query: Query<&mut ZigZag>,
+    mut heavy_query: Query<&mut Heavy>,
+    mut applied_config: ResMut<AppliedEnemyConfig>,
+    game_config: Res<GameConfig>
+) {
+    let enemy_config = &game_config.enemy;
+    let previous_config = std::mem::replace(&mut applied_config.enemy, enemy_config.clone());
+
+    for (mut enemy, mut sprite, mut collider) in enemy_query.iter_mut() {
+        let (speed, previous_speed) = (enemy_config.kind_speed(enemy.kind), previous_config.kind_speed(enemy.kind));
+        if speed != previous_speed {
+            enemy.speed = if previous_speed > 0.0 { enemy.speed * speed / previous_speed } else { speed };
+        }
+        enemy.size = enemy_config.kind_size(enemy.kind);
+        sprite.custom_size = Some(Vec2::splat(enemy.size));
+        *collider = Collider::circle(enemy.size / 2.0, CollisionLayer::Enemy);
+    }
+    for mut homing in homing_query.iter_mut() {
+        homing.turn_rate = enemy_config.chaser.turn_rate;
+    }
+    for mut zig_zag in zig_zag_query.iter_mut() {
+        zig_zag.timer.set_duration(Duration::from_secs_f32(enemy_config.zig_zag.interval));
+        zig_zag.swing = enemy_config.zig_zag.swing.copysign(zig_zag.swing);
+    }
+    for mut heavy in heavy_query.iter_mut() {
+        heavy.mass_scale = enemy_config.tank.mass;
+    }
+}
+
+pub fn steer_homing_enemies(
+    mut enemy_query: Query<(&Transform, &mut Enemy, &Homing)>,
+    player_query: Query<&Transform, With<Player>>,
+    time: Res<Time>
+) {
+    let Ok(player_transform) = player_query.get_single() else { return; };
+
+    for (transform, mut enemy, homing) in enemy_query.iter_mut() {
+        let target = (player_transform.translation - transform.translation).truncate();
+        if target == Vec2::ZERO { continue; }
+
+        let max_turn = homing.turn_rate * time.delta_seconds();
+        let turn = enemy.direction.angle_between(target).clamp(-max_turn, max_turn);
+        enemy.direction = Vec2::from_angle(turn).rotate(enemy.direction);
+    }
+}
+
+pub fn zig_zag_enemies(
+    mut enemy_query: Query<(&mut Enemy, &mut ZigZag)>,
+    time: Res<Time>
+) {
+    for (mut enemy, mut zig_zag) in enemy_query.iter_mut() {
+        if zig_zag.timer.tick(time.delta()).just_finished() {
+            enemy.direction = Vec2::from_angle(zig_zag.swing).rotate(enemy.direction);
+            zig_zag.swing = -zig_zag.swing;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    fn world_with_collisions() -> World {
+        let mut world = World::new();
+        let mut game_config = GameConfig::default();
+        game_config.enemy.collisions = true;
+        world.insert_resource(game_config);
+        world.init_resource::<SpatialHash>();
+        world
+    }
+
+    fn spawn_enemy(world: &mut World, position: Vec2, velocity: Vec2, size: f32) -> Entity {
+        let mut enemy = Enemy { kind: EnemyKind::Ball, direction: Vec2::X, speed: 0.0, size };
+        enemy.set_velocity(velocity);
+        let entity = world.spawn((Transform::from_translation(position.extend(0.0)), enemy)).id();
+        world.resource_mut::<SpatialHash>().insert(entity, position, Collider::circle(size / 2.0, CollisionLayer::Enemy));
+        entity
+    }
+
+    fn state(world: &World, entity: Entity) -> (Vec2, Vec2, f32) {
+        let enemy = world.get::<Enemy>(entity).unwrap();
+        (world.get::<Transform>(entity).unwrap().translation.truncate(), enemy.velocity(), enemy.mass())
+    }
+
+    fn assert_close(actual: Vec2, expected: Vec2) {
+        assert!(actual.abs_diff_eq(expected, expected.length().max(1.0) * 1e-4), "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn head_on_collision_conserves_momentum_and_separates() {
+        let mut world = world_with_collisions();
+        let big = spawn_enemy(&mut world, Vec2::new(100.0, 100.0), Vec2::new(200.0, 0.0), 64.0);
+        let small = spawn_enemy(&mut world, Vec2::new(140.0, 100.0), Vec2::new(-100.0, 0.0), 32.0);
+        let momentum = |world: &World| {
+            let ((_, velocity_a, mass_a), (_, velocity_b, mass_b)) = (state(world, big), state(world, small));
+            velocity_a * mass_a + velocity_b * mass_b
+        };
+        let energy = |world: &World| {
+            let ((_, velocity_a, mass_a), (_, velocity_b, mass_b)) = (state(world, big), state(world, small));
+            mass_a * velocity_a.length_squared() + mass_b * velocity_b.length_squared()
+        };
+        let (momentum_before, energy_before) = (momentum(&world), energy(&world));
+
+        world.run_system_once(collide_enemies);
+
+        let ((position_a, velocity_a, _), (position_b, velocity_b, _)) = (state(&world, big), state(&world, small));
+        assert_close(momentum(&world), momentum_before);
+        assert!((energy(&world) - energy_before).abs() <= energy_before * 1e-4);
+        assert!(position_a.distance(position_b) >= 48.0 - 1e-3);
+        assert!((velocity_b - velocity_a).x > 0.0, "still closing: {} and {}", velocity_a, velocity_b);
+    }
+
+    #[test]
+    fn enemies_staying_in_contact_collide_only_once() {
+        let mut world = world_with_collisions();
+        let a = spawn_enemy(&mut world, Vec2::new(100.0, 100.0), Vec2::new(150.0, 0.0), 64.0);
+        let b = spawn_enemy(&mut world, Vec2::new(160.0, 100.0), Vec2::new(-150.0, 0.0), 64.0);
+
+        world.run_system_once(collide_enemies);
+        let (after_first_a, after_first_b) = (state(&world, a).1, state(&world, b).1);
+        assert_close(after_first_a, Vec2::new(-150.0, 0.0));
+        assert_close(after_first_b, Vec2::new(150.0, 0.0));
+
+        world.run_system_once(collide_enemies);
+        assert_eq!(state(&world, a).1, after_first_a);
+        assert_eq!(state(&world, b).1, after_first_b);
+    }
+
+    #[test]
+    fn overlapping_enemies_moving_apart_are_only_pushed_apart() {
+        let mut world = world_with_collisions();
+        let a = spawn_enemy(&mut world, Vec2::new(100.0, 100.0), Vec2::new(-50.0, 0.0), 64.0);
+        let b = spawn_enemy(&mut world, Vec2::new(100.0, 150.0), Vec2::new(0.0, 80.0), 64.0);
+
+        world.run_system_once(collide_enemies);
+
+        let ((position_a, velocity_a, _), (position_b, velocity_b, _)) = (state(&world, a), state(&world, b));
+        assert!(position_a.distance(position_b) >= 64.0 - 1e-3);
+        assert_eq!(velocity_a, Vec2::new(-50.0, 0.0));
+        assert_eq!(velocity_b, Vec2::new(0.0, 80.0));
+    }
 }
-}
\ No newline at end of file
@@ -1,19 +1,123 @@
 use bevy::prelude::*;
+use rand::Rng;
+
+use crate::game::resources::{GameRng, Playfield};
+
+use super::SPAWN_ATTEMPTS;
 
 pub fn confine_movement(
   mut transform: Mut<'_, Transform>,
-  window: &Window,
+  playfield: &Playfield,
   size: f32
 ) {
-  let half_size = size / 2.0;
+  let (min, max) = playfield.inner_bounds(size);
   let mut translation = transform.translation;
 
-  let x_max = window.width() - half_size;
-  let y_max = window.height() - half_size;
-  if translation.x >= x_max { translation.x = x_max; }
-  if translation.x <= half_size { translation.x = half_size; }
-  if translation.y >= y_max { translation.y = y_max; }
-  if translation.y <= half_size { translation.y = half_size; }
+  if translation.x >= max.x { translation.x = max.x; }
+  if translation.x <= min.x { translation.x = min.x; }
+  if translation.y >= max.y { translation.y = max.y; }
+  if translation.y <= min.y { translation.y = min.y; }
 
   transform.translation = translation;
-}
\ No newline at end of file
+}
+
+/// Draws spawn points from `sample` until one has a `clearance` of at least 0, i.e. keeps its distance
+/// from everything it should avoid. After `SPAWN_ATTEMPTS` misses it settles for the candidate with the most.
+pub fn pick_spawn_point(
+  game_rng: &mut GameRng,
+  clearance: impl Fn(Vec2) -> f32,
+  mut sample: impl FnMut(&mut GameRng) -> Vec2
+) -> Vec2 {
+  let mut best = (Vec2::ZERO, f32::NEG_INFINITY);
+
+  for _ in 0..SPAWN_ATTEMPTS {
+    let candidate = sample(game_rng);
+    let candidate_clearance = clearance(candidate);
+
+    if candidate_clearance >= 0.0 { return candidate; }
+    if candidate_clearance > best.1 { best = (candidate, candidate_clearance); }
+  }
+  best.0
+}
+
+/// How much farther than `distance` the nearest of `points` is from `position`. Infinite without points.
+pub fn clearance_from(position: Vec2, points: impl IntoIterator<Item = Vec2>, distance: f32) -> f32 {
+  points.into_iter()
+    .map(|point| position.distance(point) - distance)
+    .fold(f32::INFINITY, f32::min)
+}
+
+/// Picks an item with a chance of its weight over the total weight. `None` if no item has any weight.
+pub fn pick_weighted<'a, T>(
+  items: &'a [T],
+  weight: impl Fn(&T) -> u32,
+  rng: &mut impl Rng
+) -> Option<&'a T> {
+  let total_weight: u32 = items.iter().map(&weight).sum();
+  if total_weight == 0 { return None; }
+
+  let mut roll = rng.gen_range(0..total_weight);
+  items.iter().find(|item| {
+    if roll < weight(item) { return true; }
+    roll -= weight(item);
+    false
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn clearance_is_distance_beyond_the_nearest_point() {
+    let points = [Vec2::new(10.0, 0.0), Vec2::new(0.0, 4.0)];
+    assert_eq!(clearance_from(Vec2::ZERO, points, 3.0), 1.0);
+    assert_eq!(clearance_from(Vec2::ZERO, [], 3.0), f32::INFINITY);
+  }
+
+  #[test]
+  fn takes_the_first_clear_candidate() {
+    let mut game_rng = GameRng::from_seed(1);
+    let mut candidates = [Vec2::new(1.0, 0.0), Vec2::new(50.0, 0.0), Vec2::new(90.0, 0.0)].into_iter();
+
+    let point = pick_spawn_point(&mut game_rng, |candidate| clearance_from(candidate, [Vec2::ZERO], 40.0), |_| {
+      candidates.next().unwrap()
+    });
+    assert_eq!(point, Vec2::new(50.0, 0.0));
+  }
+
+  #[test]
+  fn settles_for_the_clearest_candidate() {
+    let mut game_rng = GameRng::from_seed(1);
+    let mut attempts = 0;
+
+    let point = pick_spawn_point(&mut game_rng, |candidate| clearance_from(candidate, [Vec2::ZERO], 1000.0), |_| {
+      attempts += 1;
+      Vec2::new(((attempts * 7) % SPAWN_ATTEMPTS) as f32, 0.0)
+    });
+    assert_eq!(attempts, SPAWN_ATTEMPTS);
+    assert_eq!(point, Vec2::new((SPAWN_ATTEMPTS - 1) as f32, 0.0));
+  }
+
+  #[test]
+  fn weighted_pick_needs_some_weight() {
+    let mut game_rng = GameRng::from_seed(1);
+    assert_eq!(pick_weighted(&[("a", 0), ("b", 0)], |item| item.1, &mut game_rng.rng), None);
+    assert_eq!(pick_weighted(&[] as &[(&str, u32)], |item| item.1, &mut game_rng.rng), None);
+  }
+
+  #[test]
+  fn weighted_pick_follows_the_weights() {
+    let mut game_rng = GameRng::from_seed(1);
+    let items = [("never", 0), ("rare", 1), ("common", 3)];
+    let mut counts = [0; 3];
+
+    for _ in 0..4000 {
+      let picked = pick_weighted(&items, |item| item.1, &mut game_rng.rng).unwrap();
+      counts[items.iter().position(|item| item == picked).unwrap()] += 1;
+    }
+    assert_eq!(counts[0], 0);
+    assert!((800..1200).contains(&counts[1]), "rare picked {} times", counts[1]);
+    assert!((2800..3200).contains(&counts[2]), "common picked {} times", counts[2]);
+  }
+}
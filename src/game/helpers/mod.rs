@@ -1 +1,5 @@
-pub mod helpers;
\ No newline at end of file
+#[allow(clippy::module_inception)]
+pub mod helpers;
+
+/// Candidates drawn before spawn placement settles for the best one seen.
+pub const SPAWN_ATTEMPTS: usize = 30;
@@ -0,0 +1,28 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct Hud {}
+
+#[derive(Component)]
+pub struct ScoreText {}
+
+#[derive(Component)]
+pub struct SurvivalTimeText {}
+
+#[derive(Component)]
+pub struct IntensityText {}
+
+#[derive(Component)]
+pub struct LevelProgressText {}
+
+#[derive(Component)]
+pub struct EnemyCountText {}
+
+#[derive(Component)]
+pub struct StarCountText {}
+
+#[derive(Component)]
+pub struct PowerUpText {}
+
+#[derive(Component)]
+pub struct LivesText {}
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+mod components;
+pub mod resources;
+mod systems;
+
+use resources::SurvivalTime;
+use systems::*;
+
+use crate::AppState;
+
+use super::SimulationState;
+use super::director::resources::DifficultyDirector;
+use super::level::resources::LevelProgress;
+use super::player::resources::Lives;
+use super::score::resources::Score;
+
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Game), spawn_hud)
+            .add_systems(FixedUpdate, tick_survival_time
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::Running))
+                .run_if(resource_exists::<SurvivalTime>()))
+            .add_systems(Update, (
+                update_score_text.run_if(resource_exists::<Score>()),
+                update_lives_text.run_if(resource_exists::<Lives>()),
+                update_survival_time_text.run_if(resource_exists::<SurvivalTime>()),
+                update_intensity_text.run_if(resource_exists::<DifficultyDirector>()),
+                update_level_progress_text.run_if(resource_exists::<LevelProgress>()),
+                update_enemy_count_text, update_star_count_text, update_power_up_text
+            ).run_if(in_state(AppState::Game)))
+            .add_systems(OnExit(AppState::Game), despawn_hud);
+    }
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// Simulated seconds the player has stayed alive in the current run.
+#[derive(Resource, Default)]
+pub struct SurvivalTime {
+    pub seconds: f32
+}
@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::resources::SurvivalTime;
+use crate::game::director::resources::DifficultyDirector;
+use crate::game::enemy::components::Enemy;
+use crate::game::level::resources::{LevelProgress, Levels};
+use crate::game::player::resources::Lives;
+use crate::game::power_up::components::ActivePowerUps;
+use crate::game::score::resources::Score;
+use crate::game::star::components::Star;
+use crate::main_menu::styles::draw_text;
+
+pub const HUD_FONT_SIZE: f32 = 28.0;
+
+pub fn spawn_hud(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>
+) {
+    commands.insert_resource(SurvivalTime::default());
+
+    commands.spawn(
+        (NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::SpaceBetween,
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            ..default()
+        },
+        Hud {})
+    ).with_children(|parent| {
+        parent.spawn((draw_text(&asset_server, "Score: 0".to_string(), HUD_FONT_SIZE), ScoreText {}));
+        parent.spawn((draw_text(&asset_server, "Lives: 0".to_string(), HUD_FONT_SIZE), LivesText {}));
+        parent.spawn((draw_text(&asset_server, "Time: 0s".to_string(), HUD_FONT_SIZE), SurvivalTimeText {}));
+        parent.spawn((draw_text(&asset_server, "Intensity: 0".to_string(), HUD_FONT_SIZE), IntensityText {}));
+        parent.spawn((draw_text(&asset_server, String::new(), HUD_FONT_SIZE), LevelProgressText {}));
+        parent.spawn((draw_text(&asset_server, "Enemies: 0".to_string(), HUD_FONT_SIZE), EnemyCountText {}));
+        parent.spawn((draw_text(&asset_server, "Stars: 0".to_string(), HUD_FONT_SIZE), StarCountText {}));
+        parent.spawn((draw_text(&asset_server, String::new(), HUD_FONT_SIZE), PowerUpText {}));
+    });
+}
+
+pub fn despawn_hud(
+    mut commands: Commands,
+    hud_query: Query<Entity, With<Hud>>
+) {
+    commands.remove_resource::<SurvivalTime>();
+    if let Ok(hud_entity) = hud_query.get_single() {
+        commands.entity(hud_entity).despawn_recursive();
+    }
+}
+
+pub fn tick_survival_time(
+    mut survival_time: ResMut<SurvivalTime>,
+    time: Res<Time>
+) {
+    survival_time.seconds += time.delta_seconds();
+}
+
+pub fn update_score_text(
+    score: Res<Score>,
+    mut text_query: Query<&mut Text, With<ScoreText>>
+) {
+    if !score.is_changed() { return; }
+
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = format!("Score: {}", score.value);
+    }
+}
+
+pub fn update_lives_text(
+    lives: Res<Lives>,
+    mut text_query: Query<&mut Text, With<LivesText>>
+) {
+    if !lives.is_changed() { return; }
+
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = format!("Lives: {}", lives.remaining);
+    }
+}
+
+pub fn update_survival_time_text(
+    survival_time: Res<SurvivalTime>,
+    mut text_query: Query<&mut Text, With<SurvivalTimeText>>
+) {
+    if !survival_time.is_changed() { return; }
+
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = format!("Time: {}s", survival_time.seconds.floor());
+    }
+}
+
+pub fn update_intensity_text(
+    director: Res<DifficultyDirector>,
+    mut text_query: Query<&mut Text, With<IntensityText>>
+) {
+    if !director.is_changed() { return; }
+
+    if let Ok(mut text) = text_query.get_single_mut() {
+        let intensity_text = format!("Intensity: {}", director.level);
+        if text.sections[0].value != intensity_text {
+            text.sections[0].value = intensity_text;
+        }
+    }
+}
+
+pub fn update_level_progress_text(
+    progress: Res<LevelProgress>,
+    levels: Res<Levels>,
+    mut text_query: Query<&mut Text, With<LevelProgressText>>
+) {
+    if !progress.is_changed() { return; }
+    let Some(level) = levels.levels.get(progress.level) else { return; };
+
+    if let Ok(mut text) = text_query.get_single_mut() {
+        let progress_text = format!("Level {}: {}/{} stars", progress.level + 1, progress.stars_collected, level.stars_to_clear);
+        if text.sections[0].value != progress_text {
+            text.sections[0].value = progress_text;
+        }
+    }
+}
+
+pub fn update_enemy_count_text(
+    enemy_query: Query<(), With<Enemy>>,
+    added_enemy_query: Query<(), Added<Enemy>>,
+    mut removed_enemies: RemovedComponents<Enemy>,
+    mut text_query: Query<&mut Text, With<EnemyCountText>>
+) {
+    if added_enemy_query.is_empty() && removed_enemies.read().count() == 0 { return; }
+
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = format!("Enemies: {}", enemy_query.iter().count());
+    }
+}
+
+pub fn update_star_count_text(
+    star_query: Query<(), With<Star>>,
+    added_star_query: Query<(), Added<Star>>,
+    mut removed_stars: RemovedComponents<Star>,
+    mut text_query: Query<&mut Text, With<StarCountText>>
+) {
+    if added_star_query.is_empty() && removed_stars.read().count() == 0 { return; }
+
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = format!("Stars: {}", star_query.iter().count());
+    }
+}
+
+/// Lists the running power-up effects with the whole seconds each has left, e.g. `Shield 4s`.
+pub fn update_power_up_text(
+    power_up_query: Query<&ActivePowerUps>,
+    mut text_query: Query<&mut Text, With<PowerUpText>>
+) {
+    let power_up_text = power_up_query.get_single()
+        .map(|active_power_ups| active_power_ups.effects.iter()
+            .map(|(kind, timer)| format!("{} {}s", kind.name(), timer.remaining_secs().ceil()))
+            .collect::<Vec<_>>()
+            .join("  "))
+        .unwrap_or_default();
+
+    if let Ok(mut text) = text_query.get_single_mut() {
+        if text.sections[0].value != power_up_text {
+            text.sections[0].value = power_up_text;
+        }
+    }
+}
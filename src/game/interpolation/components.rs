@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+/// Translation of an entity at the start and end of the last fixed step.
+/// Gameplay works on `current`; the rendered `Transform` blends between the two.
+#[derive(Component)]
+pub struct Interpolated {
+    pub previous: Vec3,
+    pub current: Vec3
+}
+
+impl Interpolated {
+    pub fn new(translation: Vec3) -> Interpolated {
+        Interpolated { previous: translation, current: translation }
+    }
+}
@@ -0,0 +1,27 @@
+use bevy::{prelude::*, transform::TransformSystem};
+
+pub mod components;
+mod systems;
+
+use systems::*;
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum InterpolationSystemSet {
+    Restore,
+    Store
+}
+
+/// Keeps rendering smooth while gameplay advances in fixed steps: each step starts from
+/// the simulated translation, and before drawing the `Transform` is blended between the
+/// last two steps by how far the frame is into the next one.
+pub struct InterpolationPlugin;
+
+impl Plugin for InterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedUpdate, (
+                restore_simulated_translation.in_set(InterpolationSystemSet::Restore),
+                store_simulated_translation.in_set(InterpolationSystemSet::Store)
+            ))
+            .add_systems(PostUpdate, interpolate_translation.before(TransformSystem::TransformPropagate));
+    }
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use super::components::Interpolated;
+
+pub fn restore_simulated_translation(
+    mut interpolated_query: Query<(&mut Transform, &mut Interpolated)>
+) {
+    for (mut transform, mut interpolated) in interpolated_query.iter_mut() {
+        interpolated.previous = interpolated.current;
+        transform.translation = interpolated.current;
+    }
+}
+
+pub fn store_simulated_translation(
+    mut interpolated_query: Query<(&Transform, &mut Interpolated)>
+) {
+    for (transform, mut interpolated) in interpolated_query.iter_mut() {
+        interpolated.current = transform.translation;
+    }
+}
+
+pub fn interpolate_translation(
+    mut interpolated_query: Query<(&mut Transform, &Interpolated)>,
+    fixed_time: Res<Time<Fixed>>
+) {
+    let alpha = fixed_time.overstep_percentage();
+
+    for (mut transform, interpolated) in interpolated_query.iter_mut() {
+        transform.translation = interpolated.previous.lerp(interpolated.current, alpha);
+    }
+}
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+use resources::{GameMode, LevelProgress, Levels};
+use systems::*;
+
+use crate::AppState;
+
+use super::{RngSystemSet, SimulationState};
+use super::config::ConfigSystemSet;
+use super::player::PlayerSystemSet;
+
+/// Folder under `assets` holding one level per `.ron` file, played in file name order.
+pub const LEVELS_PATH: &str = "levels";
+/// Seconds the level complete banner stays up before the next level starts.
+pub const LEVEL_COMPLETE_SECONDS: f32 = 2.0;
+
+/// Level mode: scripted enemy waves per level, and collecting a level's stars moves on to the next one.
+/// Endless mode, the default, keeps the random enemy trickle.
+pub struct LevelPlugin;
+
+impl Plugin for LevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Levels>()
+            .init_resource::<GameMode>()
+            .add_systems(Startup, load_levels)
+            .add_systems(OnEnter(AppState::Game), insert_level_progress
+                .after(ConfigSystemSet)
+                .run_if(resource_equals(GameMode::Levels)))
+            .add_systems(FixedUpdate, (count_level_stars, tick_wave_timer)
+                .chain()
+                .after(PlayerSystemSet)
+                .before(RngSystemSet::Enemy)
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::Running))
+                .run_if(resource_exists::<LevelProgress>()))
+            .add_systems(OnEnter(SimulationState::LevelComplete), (complete_level, spawn_level_complete_banner)
+                .run_if(resource_exists::<LevelProgress>()))
+            .add_systems(FixedUpdate, advance_level
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::LevelComplete)))
+            .add_systems(OnExit(SimulationState::LevelComplete), despawn_level_complete_banner)
+            .add_systems(OnExit(AppState::Game), (remove_level_progress, despawn_level_complete_banner));
+    }
+}
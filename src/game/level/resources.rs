@@ -0,0 +1,283 @@
+use std::{f32::consts::TAU, fs, path::{Path, PathBuf}};
+
+use bevy::{prelude::*, asset::io::file::FileAssetReader};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{LEVELS_PATH, LEVEL_COMPLETE_SECONDS};
+use crate::game::config::MAX_SPAWNED_ENTITIES;
+use crate::game::enemy::components::EnemyKind;
+use crate::game::resources::{GameRng, Playfield};
+
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameMode {
+    #[default]
+    Endless,
+    Levels
+}
+
+impl GameMode {
+    pub fn name(self) -> &'static str {
+        match self {
+            GameMode::Endless => "Endless",
+            GameMode::Levels => "Levels"
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Level {
+    pub name: String,
+    pub stars_to_clear: u32,
+    pub waves: Vec<Wave>
+}
+
+/// A group of enemies spawned together, `delay` seconds after the previous wave (or the level start).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Wave {
+    pub count: usize,
+    #[serde(default)]
+    pub enemy: EnemyKind,
+    #[serde(default)]
+    pub pattern: SpawnPattern,
+    #[serde(default)]
+    pub delay: f32
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum SpawnPattern {
+    #[default]
+    Random,
+    Edges,
+    Corners,
+    Ring
+}
+
+impl SpawnPattern {
+    /// Where the `index`th of `count` enemies of a wave appears.
+    pub fn position(self, index: usize, count: usize, playfield: &Playfield, size: f32, game_rng: &mut GameRng) -> Vec2 {
+        let (min, max) = playfield.inner_bounds(size);
+
+        match self {
+            SpawnPattern::Random => playfield.inner_point_at(game_rng.random_fraction(), size),
+            SpawnPattern::Edges => {
+                let along = game_rng.rng.gen::<f32>();
+                let (start, end) = match game_rng.rng.gen_range(0..4) {
+                    0 => (min, Vec2::new(min.x, max.y)),
+                    1 => (Vec2::new(max.x, min.y), max),
+                    2 => (min, Vec2::new(max.x, min.y)),
+                    _ => (Vec2::new(min.x, max.y), max)
+                };
+                start.lerp(end, along)
+            }
+            SpawnPattern::Corners => match index % 4 {
+                0 => min,
+                1 => Vec2::new(max.x, min.y),
+                2 => max,
+                _ => Vec2::new(min.x, max.y)
+            },
+            SpawnPattern::Ring => {
+                let angle = TAU * index as f32 / count.max(1) as f32;
+                let radius = (max - min).min_element() / 2.0;
+                playfield.center() + Vec2::new(angle.cos(), angle.sin()) * radius
+            }
+        }
+    }
+}
+
+impl Level {
+    pub fn load(path: &Path) -> Result<Level, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|error| format!("could not read {}: {}", path.display(), error))?;
+        let level: Level = ron::from_str(&contents)
+            .map_err(|error| format!("could not parse {}: {}", path.display(), error))?;
+
+        level.validate()
+            .map_err(|error| format!("invalid level {}: {}", path.display(), error))?;
+        Ok(level)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let mut problems = Vec::new();
+
+        if self.stars_to_clear == 0 {
+            problems.push("stars_to_clear must be greater than 0".to_string());
+        }
+        if self.waves.is_empty() {
+            problems.push("waves must list at least one wave".to_string());
+        }
+        for (index, wave) in self.waves.iter().enumerate() {
+            if wave.count == 0 {
+                problems.push(format!("waves[{}].count must be at least 1", index));
+            }
+            if wave.count > MAX_SPAWNED_ENTITIES {
+                problems.push(format!("waves[{}].count must be at most {}, got {}", index, MAX_SPAWNED_ENTITIES, wave.count));
+            }
+            if !wave.delay.is_finite() || wave.delay < 0.0 {
+                problems.push(format!("waves[{}].delay must be at least 0, got {}", index, wave.delay));
+            }
+        }
+
+        if problems.is_empty() { Ok(()) } else { Err(problems.join("; ")) }
+    }
+}
+
+/// Levels available in level mode, in play order.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct Levels {
+    pub levels: Vec<Level>
+}
+
+impl Levels {
+    pub fn path() -> PathBuf {
+        FileAssetReader::get_base_path().join("assets").join(LEVELS_PATH)
+    }
+
+    /// Loads every `.ron` file in the folder. Files that fail to load are reported and left out.
+    pub fn load(path: &Path) -> Levels {
+        let mut files: Vec<PathBuf> = match fs::read_dir(path) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|file| file.extension().is_some_and(|extension| extension == "ron"))
+                .collect(),
+            Err(_) => Vec::new()
+        };
+        files.sort();
+
+        let mut levels = Levels::default();
+        for file in files {
+            match Level::load(&file) {
+                Ok(level) => levels.levels.push(level),
+                Err(error) => println!("Skipping level: {}", error)
+            }
+        }
+        levels
+    }
+}
+
+/// Where the player is within level mode. Only present during a level mode run.
+#[derive(Resource, Debug)]
+pub struct LevelProgress {
+    pub level: usize,
+    pub stars_collected: u32,
+    pub next_wave: usize,
+    pub wave_timer: Timer,
+    pub complete_timer: Timer
+}
+
+impl LevelProgress {
+    pub fn start(level: usize, levels: &Levels) -> LevelProgress {
+        let first_delay = levels.levels.get(level)
+            .and_then(|level| level.waves.first())
+            .map_or(0.0, |wave| wave.delay);
+
+        LevelProgress {
+            level,
+            stars_collected: 0,
+            next_wave: 0,
+            wave_timer: Timer::from_seconds(first_delay, TimerMode::Once),
+            complete_timer: Timer::from_seconds(LEVEL_COMPLETE_SECONDS, TimerMode::Once)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level() -> Level {
+        Level {
+            name: "Test".to_string(),
+            stars_to_clear: 5,
+            waves: vec![Wave { count: 3, enemy: EnemyKind::Ball, pattern: SpawnPattern::Random, delay: 0.0 }]
+        }
+    }
+
+    #[test]
+    fn a_level_with_stars_and_waves_is_valid() {
+        assert_eq!(level().validate(), Ok(()));
+    }
+
+    #[test]
+    fn needs_stars_and_waves() {
+        let mut level = level();
+        level.stars_to_clear = 0;
+        level.waves.clear();
+
+        let error = level.validate().unwrap_err();
+        assert!(error.contains("stars_to_clear must be greater than 0"));
+        assert!(error.contains("waves must list at least one wave"));
+    }
+
+    #[test]
+    fn rejects_bad_waves() {
+        let mut level = level();
+        level.waves[0].count = 0;
+        level.waves.push(Wave { count: MAX_SPAWNED_ENTITIES + 1, enemy: EnemyKind::Ball, pattern: SpawnPattern::Ring, delay: -1.0 });
+        level.waves.push(Wave { count: 1, enemy: EnemyKind::Ball, pattern: SpawnPattern::Ring, delay: f32::NAN });
+
+        let error = level.validate().unwrap_err();
+        assert!(error.contains("waves[0].count must be at least 1"));
+        assert!(error.contains(&format!("waves[1].count must be at most {}", MAX_SPAWNED_ENTITIES)));
+        assert!(error.contains("waves[1].delay must be at least 0, got -1"));
+        assert!(error.contains("waves[2].delay must be at least 0, got NaN"));
+        assert_eq!(error.split("; ").count(), 4);
+    }
+
+    #[test]
+    fn shipped_levels_load() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets").join(LEVELS_PATH);
+        let files = fs::read_dir(&path).unwrap().count();
+        assert!(files > 0);
+        assert_eq!(Levels::load(&path).levels.len(), files);
+    }
+
+    #[test]
+    fn corners_cycle_through_the_inner_corners() {
+        let playfield = Playfield::default();
+        let mut game_rng = GameRng::from_seed(0);
+        let (min, max) = playfield.inner_bounds(32.0);
+
+        let corners: Vec<Vec2> = (0..5)
+            .map(|index| SpawnPattern::Corners.position(index, 5, &playfield, 32.0, &mut game_rng))
+            .collect();
+        assert_eq!(corners, vec![min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y), min]);
+    }
+
+    #[test]
+    fn ring_spaces_enemies_evenly_around_the_center() {
+        let playfield = Playfield::default();
+        let mut game_rng = GameRng::from_seed(0);
+        let (min, max) = playfield.inner_bounds(32.0);
+        let radius = (max - min).min_element() / 2.0;
+
+        let ring: Vec<Vec2> = (0..4)
+            .map(|index| SpawnPattern::Ring.position(index, 4, &playfield, 32.0, &mut game_rng))
+            .collect();
+        for position in &ring {
+            assert!((position.distance(playfield.center()) - radius).abs() < 1e-3);
+        }
+        assert!(ring[0].abs_diff_eq(playfield.center() + Vec2::X * radius, 1e-3));
+        assert!(ring[1].abs_diff_eq(playfield.center() + Vec2::Y * radius, 1e-3));
+    }
+
+    #[test]
+    fn edges_and_random_stay_inside_the_playfield() {
+        let playfield = Playfield::default();
+        let mut game_rng = GameRng::from_seed(0);
+        let (min, max) = playfield.inner_bounds(32.0);
+
+        for pattern in [SpawnPattern::Edges, SpawnPattern::Random] {
+            for index in 0..50 {
+                let position = pattern.position(index, 50, &playfield, 32.0, &mut game_rng);
+                assert!(position.cmpge(min).all() && position.cmple(max).all(), "{:?} put {} outside", pattern, position);
+                if pattern == SpawnPattern::Edges {
+                    let on_edge = position.x == min.x || position.x == max.x || position.y == min.y || position.y == max.y;
+                    assert!(on_edge, "{} is not on an edge", position);
+                }
+            }
+        }
+    }
+}
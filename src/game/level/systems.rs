@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+
+use super::components::LevelCompleteBanner;
+use super::resources::{GameMode, LevelProgress, Levels};
+use crate::events::{GameOver, StarCollected};
+use crate::game::SimulationState;
+use crate::game::enemy::components::Enemy;
+use crate::game::score::resources::Score;
+use crate::main_menu::styles::draw_text;
+
+pub fn load_levels(
+    mut commands: Commands,
+    mut game_mode: ResMut<GameMode>
+) {
+    let path = Levels::path();
+    let levels = Levels::load(&path);
+    println!("Loaded {} levels from {}", levels.levels.len(), path.display());
+
+    if levels.levels.is_empty() && *game_mode == GameMode::Levels {
+        println!("No levels to play, falling back to endless mode");
+        *game_mode = GameMode::Endless;
+    }
+    commands.insert_resource(levels);
+}
+
+pub fn insert_level_progress(
+    mut commands: Commands,
+    levels: Res<Levels>
+) {
+    if let Some(level) = levels.levels.first() {
+        println!("Level 1: {}", level.name);
+    }
+    commands.insert_resource(LevelProgress::start(0, &levels));
+}
+
+pub fn remove_level_progress(
+    mut commands: Commands
+) {
+    commands.remove_resource::<LevelProgress>();
+}
+
+pub fn count_level_stars(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut progress: ResMut<LevelProgress>,
+    levels: Res<Levels>,
+    mut next_simulation_state: ResMut<NextState<SimulationState>>
+) {
+    let Some(level) = levels.levels.get(progress.level) else { return; };
+
+    for _ in star_collected_event_reader.read() {
+        progress.stars_collected += 1;
+        if progress.stars_collected == level.stars_to_clear {
+            next_simulation_state.set(SimulationState::LevelComplete);
+        }
+    }
+}
+
+pub fn tick_wave_timer(
+    mut progress: ResMut<LevelProgress>,
+    time: Res<Time>
+) {
+    progress.wave_timer.tick(time.delta());
+}
+
+/// Clears the field so the next level starts with only its own waves.
+pub fn complete_level(
+    mut commands: Commands,
+    enemy_query: Query<Entity, With<Enemy>>,
+    progress: Res<LevelProgress>
+) {
+    println!("Level {} complete", progress.level + 1);
+    for enemy_entity in enemy_query.iter() {
+        commands.entity(enemy_entity).despawn();
+    }
+}
+
+pub fn spawn_level_complete_banner(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    progress: Res<LevelProgress>,
+    levels: Res<Levels>
+) {
+    let next_level_text = match levels.levels.get(progress.level + 1) {
+        Some(level) => format!("Next: {}", level.name),
+        None => "All levels cleared!".to_string()
+    };
+
+    commands.spawn(
+        (NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            ..default()
+        },
+        LevelCompleteBanner {})
+    ).with_children(|parent| {
+        parent.spawn(draw_text(&asset_server, format!("Level {} complete!", progress.level + 1), 64.0));
+        parent.spawn(draw_text(&asset_server, next_level_text, 32.0));
+    });
+}
+
+pub fn despawn_level_complete_banner(
+    mut commands: Commands,
+    banner_query: Query<Entity, With<LevelCompleteBanner>>
+) {
+    if let Ok(banner_entity) = banner_query.get_single() {
+        commands.entity(banner_entity).despawn_recursive();
+    }
+}
+
+/// Starts the next level once the banner has been shown; clearing the last level ends the run.
+/// Runs on the fixed timestep like `lose_life`, so every `Update` system handling `GameOver` sees it in the same frame.
+pub fn advance_level(
+    mut progress: ResMut<LevelProgress>,
+    levels: Res<Levels>,
+    score: Res<Score>,
+    mut next_simulation_state: ResMut<NextState<SimulationState>>,
+    mut game_over_event_writer: EventWriter<GameOver>,
+    time: Res<Time>
+) {
+    if !progress.complete_timer.tick(time.delta()).just_finished() { return; }
+
+    let next_level = progress.level + 1;
+    match levels.levels.get(next_level) {
+        Some(level) => {
+            println!("Level {}: {}", next_level + 1, level.name);
+            *progress = LevelProgress::start(next_level, &levels);
+            next_simulation_state.set(SimulationState::Running);
+        }
+        None => {
+            println!("All levels cleared!");
+            game_over_event_writer.send(GameOver { score: score.value });
+        }
+    }
+}
@@ -1,19 +1,38 @@
 use bevy::prelude::*;
 
+pub mod collision;
+pub mod config;
+pub mod director;
 pub mod enemy;
+mod hud;
+pub mod interpolation;
+pub mod level;
 mod player;
-mod score;
-mod star;
+pub mod power_up;
+pub mod resources;
+pub mod score;
+pub mod star;
 pub mod helpers;
 mod systems;
 
+use collision::{CollisionPlugin, CollisionSystemSet};
+use config::{ConfigPlugin, ConfigSystemSet};
+use director::DirectorPlugin;
 use enemy::EnemyPlugin;
-use player::PlayerPlugin;
+use hud::HudPlugin;
+use interpolation::{InterpolationPlugin, InterpolationSystemSet};
+use level::LevelPlugin;
+use player::{PlayerPlugin, PlayerSystemSet};
+use power_up::PowerUpPlugin;
+use resources::{GameRng, GameSeed, Playfield};
 use score::ScorePlugin;
 use star::StarPlugin;
 use systems::*;
 
-use crate::{events::GameOver, AppState};
+use crate::{events::{GameOver, PlayerHit, StarCollected}, AppState};
+use crate::replay::resources::ReplayPlayback;
+
+pub const GAMEPLAY_TIMESTEP_HZ: f64 = 60.0;
 
 pub struct GamePlugin;
 
@@ -22,16 +41,44 @@ impl Plugin for GamePlugin {
         app 
         .add_state::<SimulationState>()
         .add_event::<GameOver>()
-        .add_systems(OnEnter(AppState::Game), pause_simulation)
-        .add_plugins((EnemyPlugin, PlayerPlugin, ScorePlugin, StarPlugin))
+        .add_event::<PlayerHit>()
+        .add_event::<StarCollected>()
+        .init_resource::<Playfield>()
+        .init_resource::<GameSeed>()
+        .init_resource::<GameRng>()
+        .insert_resource(Time::<Fixed>::from_hz(GAMEPLAY_TIMESTEP_HZ))
+        .configure_sets(OnEnter(AppState::Game), (RngSystemSet::Seed, ConfigSystemSet, RngSystemSet::Enemy, CollisionSystemSet::Detect, RngSystemSet::Star).chain())
+        .configure_sets(FixedUpdate, (
+            InterpolationSystemSet::Restore,
+            CollisionSystemSet::Detect, CollisionSystemSet::React,
+            PlayerSystemSet, RngSystemSet::Enemy, RngSystemSet::Star, RngSystemSet::PowerUp,
+            InterpolationSystemSet::Store
+        ).chain())
+        .add_systems(OnEnter(AppState::Game), (
+            pause_simulation,
+            (reset_fixed_time, seed_game_rng.run_if(not(resource_exists::<ReplayPlayback>()))).in_set(RngSystemSet::Seed),
+            // Opening stars are placed around the opening enemies, so those have to be in the spatial hash by then.
+            apply_deferred.after(RngSystemSet::Enemy).before(CollisionSystemSet::Detect)
+        ))
+        .add_plugins((CollisionPlugin, ConfigPlugin, DirectorPlugin, EnemyPlugin, HudPlugin, InterpolationPlugin, LevelPlugin, PlayerPlugin, PowerUpPlugin, ScorePlugin, StarPlugin))
         .add_systems(Update, toggle_simulation.run_if(in_state(AppState::Game)))
         .add_systems(OnExit(AppState::Game), resume_simulation);
     }
 }
 
+/// Systems drawing from `GameRng` run in a fixed order so a seed always replays the same run.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum RngSystemSet {
+    Seed,
+    Enemy,
+    Star,
+    PowerUp
+}
+
 #[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
 pub enum SimulationState {
     #[default] 
     Running,
-    Paused
-}
\ No newline at end of file
+    Paused,
+    LevelComplete
+}
@@ -1,4 +1,27 @@
 use bevy::prelude::*;
 
 #[derive(Component)]
-pub struct Player {}
\ No newline at end of file
+pub struct Player {}
+
+/// Keeps enemies from hurting a freshly respawned player until `timer` runs out.
+#[derive(Component)]
+pub struct Invulnerability {
+    pub timer: Timer
+}
+
+impl Invulnerability {
+    pub fn from_seconds(seconds: f32) -> Invulnerability {
+        Invulnerability { timer: Timer::from_seconds(seconds, TimerMode::Once) }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.timer.remaining_secs() > 0.0
+    }
+}
+
+/// Enemies touching the player, kept up to date from collision events. An enemy still on the player
+/// once invulnerability wears off or a shield is used up lands its hit then.
+#[derive(Component, Default)]
+pub struct EnemyContacts {
+    pub enemies: Vec<Entity>
+}
@@ -1,14 +1,20 @@
 use bevy::prelude::*;
 
 pub mod components;
+pub mod resources;
 mod systems;
 
 use systems::*;
 use crate::AppState;
 use super::SimulationState;
+use super::collision::CollisionSystemSet;
+use super::config::ConfigSystemSet;
 
-pub const PLAYER_SIZE: f32 = 64.0;
-pub const PLAYER_SPEED: f32 = 500.0;
+/// Blinks per second of a player who cannot be hit.
+pub const PLAYER_BLINK_RATE: f32 = 5.0;
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct PlayerSystemSet;
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub struct MovementSystemSet;
@@ -21,12 +27,19 @@ pub struct PlayerPlugin;
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app
-            .configure_sets(Update, MovementSystemSet.before(ConfinementSystemSet))
-            .add_systems(OnEnter(AppState::Game), spawn_player)
-            .add_systems(Update, (
-                player_movement, confine_player_movement, 
-                enemy_hit_player, player_hit_star
-            ).in_set(MovementSystemSet).run_if(in_state(AppState::Game)).run_if(in_state(SimulationState::Running)))
-            .add_systems(OnExit(AppState::Game), despawn_player);
+            .configure_sets(FixedUpdate, MovementSystemSet.before(ConfinementSystemSet))
+            .add_systems(OnEnter(AppState::Game), (spawn_player, insert_lives).after(ConfigSystemSet))
+            .add_systems(FixedUpdate, (
+                player_movement.in_set(MovementSystemSet),
+                confine_player_movement.in_set(ConfinementSystemSet),
+                tick_invulnerability.after(ConfinementSystemSet)
+            ).in_set(PlayerSystemSet).run_if(in_state(AppState::Game)).run_if(in_state(SimulationState::Running)))
+            .add_systems(FixedUpdate, (enemy_hit_player, lose_life, respawn_player, player_hit_star)
+                .chain()
+                .in_set(CollisionSystemSet::React)
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::Running)))
+            .add_systems(Update, blink_invulnerable_player.run_if(in_state(AppState::Game)))
+            .add_systems(OnExit(AppState::Game), (despawn_player, remove_lives));
     }
 }
\ No newline at end of file
@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// Lives the player has left in the current run, counting the one in play.
+#[derive(Resource)]
+pub struct Lives {
+    pub remaining: u32
+}
@@ -1,29 +1,42 @@
-use bevy::{prelude::*, window::PrimaryWindow};
+use bevy::prelude::*;
 use bevy::audio::VolumeLevel;
 
-use super::components::Player;
-use super::{PLAYER_SIZE, PLAYER_SPEED};
-use crate::game::helpers::helpers::confine_movement;
-use crate::events::GameOver;
-use crate::game::enemy::{ENEMY_SIZE, components::Enemy};
+use super::PLAYER_BLINK_RATE;
+use super::components::{EnemyContacts, Invulnerability, Player};
+use super::resources::Lives;
+use crate::game::collision::components::Collider;
+use crate::game::collision::resources::{CollisionLayer, SpatialHash};
+use crate::game::config::resources::GameConfig;
+use crate::game::helpers::helpers::{clearance_from, confine_movement, pick_spawn_point};
+use crate::game::interpolation::components::Interpolated;
+use crate::game::resources::{GameRng, Playfield};
+use crate::events::{CollisionEnded, CollisionStarted, GameOver, PlayerHit, StarCollected};
+use crate::game::power_up::components::{ActivePowerUps, PowerUpKind};
 use crate::game::score::resources::*;
-use crate::game::star::{STAR_SIZE, components::Star};
+use crate::game::star::components::{Star, StarLifetime};
 
 pub fn spawn_player(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    playfield: Res<Playfield>,
+    game_config: Res<GameConfig>,
     asset_server: Res<AssetServer>
 ) {
-    let window = window_query.get_single().unwrap();
+    let translation = playfield.center().extend(0.0);
 
     commands.spawn(
         (
             SpriteBundle {
-                transform: Transform::from_xyz(window.width() / 2.0, window.height() / 2.0, 0.0),
+                transform: Transform::from_translation(translation),
                 texture: asset_server.load("sprites/ball_blue_large.png"),
+                sprite: Sprite { custom_size: Some(Vec2::splat(game_config.player.size)), ..default() },
                 ..default()
             },
-            Player {}
+            Player {},
+            Collider::circle(game_config.player.size / 2.0, CollisionLayer::Player),
+            ActivePowerUps::default(),
+            Invulnerability::from_seconds(0.0),
+            EnemyContacts::default(),
+            Interpolated::new(translation)
         )
     );
 }
@@ -50,10 +63,11 @@ fn key_from_pair_pressed (
 
 pub fn player_movement(
     keyboard_input: Res<Input<KeyCode>>,
-    mut player_query: Query<&mut Transform, With<Player>>,
+    mut player_query: Query<(&mut Transform, &ActivePowerUps), With<Player>>,
+    game_config: Res<GameConfig>,
     time: Res<Time>,
 ) {
-    if let Ok(mut transform) = player_query.get_single_mut() {
+    if let Ok((mut transform, active_power_ups)) = player_query.get_single_mut() {
         let mut direction = Vec3::ZERO;
         let (mut x, mut y) = (0.0, 0.0);
 
@@ -67,41 +81,59 @@ pub fn player_movement(
             direction = direction.normalize();
         }
         
-        transform.translation += direction * PLAYER_SPEED * time.delta_seconds();        
+        let speed = game_config.player.speed * active_power_ups.factor(PowerUpKind::SpeedBoost, game_config.power_up.speed_boost);
+        transform.translation += direction * speed * time.delta_seconds();        
     }
 }
 
 pub fn confine_player_movement(
     mut player_query: Query<&mut Transform, With<Player>>,
-    window_query: Query<&Window, With<PrimaryWindow>>
+    playfield: Res<Playfield>,
+    game_config: Res<GameConfig>
 ) {
     if let Ok(transform) = player_query.get_single_mut() {
-        let window = window_query.get_single().unwrap();
-        confine_movement(transform, window, PLAYER_SIZE);
+        confine_movement(transform, &playfield, game_config.player.size);
     }
 }
 
+/// Lets the first enemy touching the player land a hit, unless the player is invulnerable.
+/// A shield takes the hit instead, destroying the enemy.
 pub fn enemy_hit_player(
     mut commands: Commands,
-    mut game_over_event_writer: EventWriter<GameOver>,
-    mut player_query: Query<(Entity, &Transform), With<Player>>,
-    enemy_query: Query<&Transform, With<Enemy>>,
-    asset_server: Res<AssetServer>,
-    score: Res<Score>
+    mut collision_started_event_reader: EventReader<CollisionStarted>,
+    mut collision_ended_event_reader: EventReader<CollisionEnded>,
+    mut player_hit_event_writer: EventWriter<PlayerHit>,
+    mut player_query: Query<(&mut EnemyContacts, &mut ActivePowerUps, &Invulnerability), With<Player>>,
+    asset_server: Res<AssetServer>
 ) {
-    let mut is_collision = false;
-    if let Ok((player_entity, player_tranform)) = player_query.get_single_mut() {
+    if let Ok((mut enemy_contacts, mut active_power_ups, invulnerability)) = player_query.get_single_mut() {
+        for event in collision_ended_event_reader.read() {
+            let Some((_, enemy_entity)) = event.contact.between(CollisionLayer::Player, CollisionLayer::Enemy) else { continue; };
+            enemy_contacts.enemies.retain(|entity| *entity != enemy_entity);
+        }
+        for event in collision_started_event_reader.read() {
+            let Some((_, enemy_entity)) = event.contact.between(CollisionLayer::Player, CollisionLayer::Enemy) else { continue; };
+            enemy_contacts.enemies.push(enemy_entity);
+        }
 
-        for enemy_transform in enemy_query.iter() {
-            let distance = player_tranform.translation.distance(enemy_transform.translation);
-            let min_distance = PLAYER_SIZE / 2.0 + ENEMY_SIZE / 2.0;
+        if invulnerability.is_active() { return; }
+        let Some(&enemy_entity) = enemy_contacts.enemies.first() else { return; };
 
-            is_collision = distance < min_distance;
-            if is_collision { break; }
-        }
-        if is_collision {
+        if active_power_ups.remove(PowerUpKind::Shield) {
+            println!("The shield absorbed the hit");
+            enemy_contacts.enemies.remove(0);
+            commands.entity(enemy_entity).despawn();
+            commands.spawn(
+                AudioBundle {
+                    source: asset_server.load("audio/pluck_001.ogg"),
+                    settings: PlaybackSettings {
+                        mode: bevy::audio::PlaybackMode::Remove,
+                        ..default()
+                    }
+                },
+            );
+        } else {
             println!("Oh no! BAM!");
-            commands.entity(player_entity).despawn();
             commands.spawn(
                 AudioBundle {
                     source: asset_server.load("audio/explosionCrunch_000.ogg"),
@@ -111,35 +143,120 @@ pub fn enemy_hit_player(
                     }
                 },
             );
+            player_hit_event_writer.send(PlayerHit {});
+        }
+    }
+}
+
+/// Takes a life for every hit and ends the game once none are left.
+pub fn lose_life(
+    mut commands: Commands,
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut game_over_event_writer: EventWriter<GameOver>,
+    player_query: Query<Entity, With<Player>>,
+    mut lives: ResMut<Lives>,
+    score: Res<Score>
+) {
+    for _ in player_hit_event_reader.read() {
+        lives.remaining = lives.remaining.saturating_sub(1);
+        if lives.remaining > 0 {
+            println!("Lives left: {}", lives.remaining);
+            continue;
+        }
+        if let Ok(player_entity) = player_query.get_single() {
+            commands.entity(player_entity).despawn();
             game_over_event_writer.send(GameOver { score: score.value });
         }
     }
 }
 
+/// Moves a player who survived a hit to a spot clear of enemies and makes them briefly invulnerable.
+pub fn respawn_player(
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut player_query: Query<(&mut Transform, &mut Interpolated, &mut Invulnerability), With<Player>>,
+    spatial_hash: Res<SpatialHash>,
+    lives: Res<Lives>,
+    playfield: Res<Playfield>,
+    game_config: Res<GameConfig>,
+    mut game_rng: ResMut<GameRng>
+) {
+    if player_hit_event_reader.read().count() == 0 || lives.remaining == 0 { return; }
+    let Ok((mut transform, mut interpolated, mut invulnerability)) = player_query.get_single_mut() else { return; };
+
+    let safe_spawn_radius = game_config.enemy.safe_spawn_radius;
+    let clearance = |candidate| {
+        let enemy_positions = spatial_hash.query(candidate, safe_spawn_radius, CollisionLayer::Enemy).map(|entry| entry.position);
+        clearance_from(candidate, enemy_positions, safe_spawn_radius)
+    };
+    let position = pick_spawn_point(&mut game_rng, clearance, |game_rng| {
+        playfield.inner_point_at(game_rng.random_fraction(), game_config.player.size)
+    }).extend(0.0);
+
+    transform.translation = position;
+    interpolated.previous = position;
+    *invulnerability = Invulnerability::from_seconds(game_config.player.invulnerable_time);
+}
+
+pub fn tick_invulnerability(
+    mut player_query: Query<(&mut Invulnerability, &mut Sprite)>,
+    time: Res<Time>
+) {
+    for (mut invulnerability, mut sprite) in player_query.iter_mut() {
+        if invulnerability.timer.tick(time.delta()).just_finished() {
+            sprite.color.set_a(1.0);
+        }
+    }
+}
+
+pub fn blink_invulnerable_player(
+    mut player_query: Query<(&Invulnerability, &mut Sprite)>
+) {
+    for (invulnerability, mut sprite) in player_query.iter_mut() {
+        if !invulnerability.is_active() { continue; }
+
+        let is_dimmed = (invulnerability.timer.elapsed_secs() * PLAYER_BLINK_RATE * 2.0) as u32 % 2 == 1;
+        sprite.color.set_a(if is_dimmed { 0.2 } else { 1.0 });
+    }
+}
+
+pub fn insert_lives(
+    mut commands: Commands,
+    game_config: Res<GameConfig>
+) {
+    commands.insert_resource(Lives { remaining: game_config.player.lives });
+}
+
+pub fn remove_lives(
+    mut commands: Commands
+) {
+    commands.remove_resource::<Lives>();
+}
+
 pub fn player_hit_star(
     mut commands: Commands,
-    player_query: Query<&Transform, With<Player>>,
-    star_query: Query<(Entity, &Transform), With<Star>>,
+    mut collision_started_event_reader: EventReader<CollisionStarted>,
+    mut star_collected_event_writer: EventWriter<StarCollected>,
+    star_query: Query<(&Star, Option<&StarLifetime>)>,
+    game_config: Res<GameConfig>,
     asset_server: Res<AssetServer>,
     mut score: ResMut<Score>
 ) {
-    if let Ok(player_transform) = player_query.get_single() {
-        for (star_entity, star_transform) in star_query.iter() {
-            let distance = player_transform.translation.distance(star_transform.translation);
-            if distance < PLAYER_SIZE / 2.0 + STAR_SIZE / 2.0 {
-                score.value += 1;
-                commands.entity(star_entity).despawn();
-                commands.spawn(
-                    AudioBundle {
-                        source: asset_server.load("audio/laserLarge_000.ogg"),
-                        settings: PlaybackSettings { 
-                            mode: bevy::audio::PlaybackMode::Remove,
-                            volume: {bevy::audio::Volume::Absolute(VolumeLevel::new(0.5))},
-                            ..default()
-                        }
-                    },
-                );
-            }
-        }
+    for event in collision_started_event_reader.read() {
+        let Some((_, star_entity)) = event.contact.between(CollisionLayer::Player, CollisionLayer::Star) else { continue; };
+        let Ok((star, lifetime)) = star_query.get(star_entity) else { continue; };
+
+        score.value += star.score + lifetime.map_or(0, |lifetime| lifetime.bonus(game_config.star.max_bonus));
+        star_collected_event_writer.send(StarCollected {});
+        commands.entity(star_entity).despawn();
+        commands.spawn(
+            AudioBundle {
+                source: asset_server.load(&star.sound),
+                settings: PlaybackSettings { 
+                    mode: bevy::audio::PlaybackMode::Remove,
+                    volume: {bevy::audio::Volume::Absolute(VolumeLevel::new(0.5))},
+                    ..default()
+                }
+            },
+        );
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerUpKind {
+    Shield,
+    SpeedBoost,
+    SlowMotion,
+    Magnet
+}
+
+impl PowerUpKind {
+    pub const ALL: [PowerUpKind; 4] = [PowerUpKind::Shield, PowerUpKind::SpeedBoost, PowerUpKind::SlowMotion, PowerUpKind::Magnet];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PowerUpKind::Shield => "Shield",
+            PowerUpKind::SpeedBoost => "Speed",
+            PowerUpKind::SlowMotion => "Slow-mo",
+            PowerUpKind::Magnet => "Magnet"
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            PowerUpKind::Shield => Color::rgb(0.3, 0.9, 1.0),
+            PowerUpKind::SpeedBoost => Color::rgb(0.3, 1.0, 0.3),
+            PowerUpKind::SlowMotion => Color::rgb(0.7, 0.4, 1.0),
+            PowerUpKind::Magnet => Color::rgb(1.0, 0.4, 0.4)
+        }
+    }
+}
+
+/// A pickup waiting on the field until `lifetime` runs out.
+#[derive(Component)]
+pub struct PowerUp {
+    pub kind: PowerUpKind,
+    pub lifetime: Timer
+}
+
+/// Effects currently granted to the player, each with the time it has left.
+#[derive(Component, Default)]
+pub struct ActivePowerUps {
+    pub effects: Vec<(PowerUpKind, Timer)>
+}
+
+impl ActivePowerUps {
+    /// Starts the effect, or restarts it if it is already running.
+    pub fn activate(&mut self, kind: PowerUpKind, seconds: f32) {
+        self.remove(kind);
+        self.effects.push((kind, Timer::from_seconds(seconds, TimerMode::Once)));
+    }
+
+    /// Ends the effect early. Returns whether it was running.
+    pub fn remove(&mut self, kind: PowerUpKind) -> bool {
+        let count = self.effects.len();
+        self.effects.retain(|(active_kind, _)| *active_kind != kind);
+        self.effects.len() != count
+    }
+
+    pub fn is_active(&self, kind: PowerUpKind) -> bool {
+        self.effects.iter().any(|(active_kind, _)| *active_kind == kind)
+    }
+
+    /// `factor` while the effect runs, 1 otherwise.
+    pub fn factor(&self, kind: PowerUpKind, factor: f32) -> f32 {
+        if self.is_active(kind) { factor } else { 1.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn activating_again_refreshes_instead_of_stacking() {
+        let mut active_power_ups = ActivePowerUps::default();
+        active_power_ups.activate(PowerUpKind::SpeedBoost, 5.0);
+        active_power_ups.effects[0].1.tick(Duration::from_secs(3));
+
+        active_power_ups.activate(PowerUpKind::SpeedBoost, 5.0);
+        assert_eq!(active_power_ups.effects.len(), 1);
+        assert_eq!(active_power_ups.effects[0].1.remaining_secs(), 5.0);
+    }
+
+    #[test]
+    fn factor_applies_only_while_active() {
+        let mut active_power_ups = ActivePowerUps::default();
+        assert_eq!(active_power_ups.factor(PowerUpKind::SlowMotion, 0.5), 1.0);
+
+        active_power_ups.activate(PowerUpKind::SlowMotion, 5.0);
+        assert_eq!(active_power_ups.factor(PowerUpKind::SlowMotion, 0.5), 0.5);
+        assert_eq!(active_power_ups.factor(PowerUpKind::SpeedBoost, 1.5), 1.0);
+    }
+
+    #[test]
+    fn a_shield_absorbs_exactly_one_hit() {
+        let mut active_power_ups = ActivePowerUps::default();
+        active_power_ups.activate(PowerUpKind::Shield, 5.0);
+        active_power_ups.activate(PowerUpKind::Magnet, 5.0);
+
+        assert!(active_power_ups.remove(PowerUpKind::Shield));
+        assert!(!active_power_ups.remove(PowerUpKind::Shield));
+        assert!(active_power_ups.is_active(PowerUpKind::Magnet));
+    }
+}
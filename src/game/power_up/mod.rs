@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod resources;
+mod systems;
+
+use systems::*;
+
+use crate::AppState;
+
+use super::{RngSystemSet, SimulationState};
+use super::collision::CollisionSystemSet;
+use super::config::ConfigSystemSet;
+
+/// Sprite every pickup is drawn with, tinted by its `PowerUpKind`.
+pub const POWER_UP_SPRITE: &str = "sprites/ball_blue_large.png";
+
+/// Pickups granting the player a timed shield, speed boost, enemy slow-down or star magnet.
+/// The effects themselves live in `ActivePowerUps` on the player.
+pub struct PowerUpPlugin;
+
+impl Plugin for PowerUpPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Game), insert_power_up_spawn_timer.after(ConfigSystemSet))
+            .add_systems(FixedUpdate,
+                (
+                    (tick_power_up_spawn_timer, expire_power_ups, spawn_power_ups_over_time, tick_active_power_ups, attract_stars)
+                        .chain()
+                        .in_set(RngSystemSet::PowerUp),
+                    player_hit_power_up.in_set(CollisionSystemSet::React)
+                ).run_if(in_state(AppState::Game)).run_if(in_state(SimulationState::Running))
+            )
+            .add_systems(OnExit(AppState::Game), (despawn_power_ups, remove_power_up_spawn_timer));
+    }
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct PowerUpSpawnTimer {
+    pub timer: Timer
+}
+
+impl PowerUpSpawnTimer {
+    pub fn from_seconds(seconds: f32) -> PowerUpSpawnTimer {
+        PowerUpSpawnTimer { timer: Timer::from_seconds(seconds, TimerMode::Repeating) }
+    }
+}
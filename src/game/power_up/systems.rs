@@ -0,0 +1,188 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, audio::VolumeLevel};
+use rand::Rng;
+
+use super::POWER_UP_SPRITE;
+use super::components::*;
+use super::resources::PowerUpSpawnTimer;
+use crate::events::CollisionStarted;
+use crate::game::collision::components::Collider;
+use crate::game::collision::resources::{CollisionLayer, SpatialHash};
+use crate::game::config::resources::GameConfig;
+use crate::game::helpers::helpers::{clearance_from, pick_spawn_point};
+use crate::game::player::components::Player;
+use crate::game::resources::{GameRng, Playfield};
+use crate::game::star::components::Star;
+
+pub fn insert_power_up_spawn_timer(
+    mut commands: Commands,
+    game_config: Res<GameConfig>
+) {
+    commands.insert_resource(PowerUpSpawnTimer::from_seconds(game_config.power_up.spawn_time));
+}
+
+pub fn remove_power_up_spawn_timer(
+    mut commands: Commands
+) {
+    commands.remove_resource::<PowerUpSpawnTimer>();
+}
+
+pub fn despawn_power_ups(
+    mut commands: Commands,
+    power_up_query: Query<Entity, With<PowerUp>>
+) {
+    for power_up_entity in power_up_query.iter() {
+        commands.entity(power_up_entity).despawn();
+    }
+}
+
+pub fn tick_power_up_spawn_timer(
+    mut power_up_spawn_timer: ResMut<PowerUpSpawnTimer>,
+    game_config: Res<GameConfig>,
+    time: Res<Time>
+) {
+    power_up_spawn_timer.timer.set_duration(Duration::from_secs_f32(game_config.power_up.spawn_time));
+    power_up_spawn_timer.timer.tick(time.delta());
+}
+
+pub fn expire_power_ups(
+    mut commands: Commands,
+    mut power_up_query: Query<(Entity, &mut PowerUp)>,
+    time: Res<Time>
+) {
+    for (power_up_entity, mut power_up) in power_up_query.iter_mut() {
+        if power_up.lifetime.tick(time.delta()).finished() {
+            commands.entity(power_up_entity).despawn();
+        }
+    }
+}
+
+/// Drops a random pickup somewhere the player has to travel to.
+pub fn spawn_power_ups_over_time(
+    mut commands: Commands,
+    playfield: Res<Playfield>,
+    game_config: Res<GameConfig>,
+    asset_server: Res<AssetServer>,
+    power_up_spawn_timer: Res<PowerUpSpawnTimer>,
+    player_query: Query<&Transform, With<Player>>,
+    mut game_rng: ResMut<GameRng>
+) {
+    if !power_up_spawn_timer.timer.finished() { return; }
+
+    let kind = PowerUpKind::ALL[game_rng.rng.gen_range(0..PowerUpKind::ALL.len())];
+    let size = game_config.power_up.size;
+    let clearance = |candidate| {
+        let player_positions = player_query.iter().map(|transform| transform.translation.truncate());
+        clearance_from(candidate, player_positions, game_config.enemy.safe_spawn_radius)
+    };
+    let position = pick_spawn_point(&mut game_rng, clearance, |game_rng| playfield.inner_point_at(game_rng.random_fraction(), size));
+
+    commands.spawn(
+        (
+            SpriteBundle {
+                transform: Transform::from_translation(position.extend(0.0)),
+                texture: asset_server.load(POWER_UP_SPRITE),
+                sprite: Sprite { color: kind.color(), custom_size: Some(Vec2::splat(size)), ..default() },
+                ..default()
+            },
+            PowerUp { kind, lifetime: Timer::from_seconds(game_config.power_up.lifetime, TimerMode::Once) },
+            Collider::circle(size / 2.0, CollisionLayer::PowerUp)
+        )
+    );
+}
+
+pub fn player_hit_power_up(
+    mut commands: Commands,
+    mut collision_started_event_reader: EventReader<CollisionStarted>,
+    mut player_query: Query<&mut ActivePowerUps, With<Player>>,
+    power_up_query: Query<&PowerUp>,
+    game_config: Res<GameConfig>,
+    asset_server: Res<AssetServer>
+) {
+    for event in collision_started_event_reader.read() {
+        let Some((player_entity, power_up_entity)) = event.contact.between(CollisionLayer::Player, CollisionLayer::PowerUp) else { continue; };
+        let Ok(mut active_power_ups) = player_query.get_mut(player_entity) else { continue; };
+        let Ok(power_up) = power_up_query.get(power_up_entity) else { continue; };
+
+        println!("Power-up: {}", power_up.kind.name());
+        active_power_ups.activate(power_up.kind, game_config.power_up.duration);
+        commands.entity(power_up_entity).despawn();
+        commands.spawn(
+            AudioBundle {
+                source: asset_server.load("audio/pluck_002.ogg"),
+                settings: PlaybackSettings {
+                    mode: bevy::audio::PlaybackMode::Remove,
+                    volume: bevy::audio::Volume::Absolute(VolumeLevel::new(0.5)),
+                    ..default()
+                }
+            },
+        );
+    }
+}
+
+pub fn tick_active_power_ups(
+    mut player_query: Query<&mut ActivePowerUps>,
+    time: Res<Time>
+) {
+    for mut active_power_ups in player_query.iter_mut() {
+        if active_power_ups.effects.is_empty() { continue; }
+
+        for (_, timer) in active_power_ups.effects.iter_mut() {
+            timer.tick(time.delta());
+        }
+        active_power_ups.effects.retain(|(kind, timer)| {
+            if timer.finished() { println!("{} wore off", kind.name()); }
+            !timer.finished()
+        });
+    }
+}
+
+/// Pulls stars within the magnet radius toward a player holding the magnet.
+pub fn attract_stars(
+    mut star_query: Query<&mut Transform, With<Star>>,
+    player_query: Query<(&Transform, &ActivePowerUps), Without<Star>>,
+    spatial_hash: Res<SpatialHash>,
+    game_config: Res<GameConfig>,
+    time: Res<Time>
+) {
+    let Ok((player_transform, active_power_ups)) = player_query.get_single() else { return; };
+    if !active_power_ups.is_active(PowerUpKind::Magnet) { return; }
+
+    let player_position = player_transform.translation;
+    let step = game_config.power_up.magnet_speed * time.delta_seconds();
+
+    for entry in spatial_hash.query(player_position.truncate(), game_config.power_up.magnet_radius, CollisionLayer::Star) {
+        let Ok(mut star_transform) = star_query.get_mut(entry.entity) else { continue; };
+        let offset = player_position - star_transform.translation;
+        let distance = offset.length();
+        if distance <= game_config.power_up.magnet_radius && distance > 0.0 {
+            star_transform.translation += offset / distance * step.min(distance);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn effects_wear_off_when_their_time_runs_out() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let mut active_power_ups = ActivePowerUps::default();
+        active_power_ups.activate(PowerUpKind::SpeedBoost, 2.0);
+        active_power_ups.activate(PowerUpKind::Shield, 5.0);
+        let player = world.spawn(active_power_ups).id();
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(3));
+        world.run_system_once(tick_active_power_ups);
+
+        let active_power_ups = world.get::<ActivePowerUps>(player).unwrap();
+        assert!(!active_power_ups.is_active(PowerUpKind::SpeedBoost));
+        assert!(active_power_ups.is_active(PowerUpKind::Shield));
+        assert_eq!(active_power_ups.effects[0].1.remaining_secs(), 2.0);
+    }
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+use rand::{random, rngs::StdRng, Rng, SeedableRng};
+
+pub const PLAYFIELD_WIDTH: f32 = 1280.0;
+pub const PLAYFIELD_HEIGHT: f32 = 720.0;
+
+/// Logical arena every spawn, confinement and bounce is computed against.
+/// It does not follow the OS window: the camera scales to keep it in view instead.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct Playfield {
+    pub size: Vec2,
+    pub origin: Vec2,
+    pub margin: f32
+}
+
+impl Default for Playfield {
+    fn default() -> Playfield {
+        Playfield { size: Vec2::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT), origin: Vec2::ZERO, margin: 0.0 }
+    }
+}
+
+impl Playfield {
+    pub fn center(&self) -> Vec2 {
+        self.origin + self.size / 2.0
+    }
+
+    pub fn min(&self) -> Vec2 {
+        self.origin + Vec2::splat(self.margin)
+    }
+
+    pub fn max(&self) -> Vec2 {
+        self.origin + self.size - Vec2::splat(self.margin)
+    }
+
+    /// Range the center of a ball of the given size can occupy while staying inside the arena.
+    pub fn inner_bounds(&self, size: f32) -> (Vec2, Vec2) {
+        let half_size = Vec2::splat(size / 2.0);
+        (self.min() + half_size, self.max() - half_size)
+    }
+
+    /// Maps a fraction in `0..1` on each axis onto the centers that keep a ball of the given size fully inside.
+    pub fn inner_point_at(&self, fraction: Vec2, size: f32) -> Vec2 {
+        let (min, max) = self.inner_bounds(size);
+        min + (max - min).max(Vec2::ZERO) * fraction
+    }
+}
+
+/// Seed requested for upcoming runs. `None` picks a fresh random seed every time a game starts.
+#[derive(Resource, Debug, Default)]
+pub struct GameSeed {
+    pub value: Option<u64>
+}
+
+/// Source of every random decision made during a run, so a run can be reproduced from its seed.
+#[derive(Resource)]
+pub struct GameRng {
+    pub seed: u64,
+    pub rng: StdRng
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> GameRng {
+        GameRng { seed, rng: StdRng::seed_from_u64(seed) }
+    }
+
+    pub fn random_fraction(&mut self) -> Vec2 {
+        Vec2::new(self.rng.gen::<f32>(), self.rng.gen::<f32>())
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> GameRng {
+        GameRng::from_seed(random())
+    }
+}
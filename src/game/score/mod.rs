@@ -3,7 +3,7 @@ use bevy::prelude::*;
 pub mod resources;
 mod systems;
 
-use resources::HighScores;
+use resources::{FinalScore, HighScores, PlayerName};
 use systems::*;
 
 use crate::AppState;
@@ -15,6 +15,9 @@ pub struct ScorePlugin;
 impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<HighScores>()
+            .init_resource::<FinalScore>()
+            .init_resource::<PlayerName>()
+            .add_systems(Startup, load_high_scores)
             .add_systems(OnEnter(AppState::Game), insert_score)
             .add_systems(Update, update_score
                 .run_if(in_state(AppState::Game)).run_if(in_state(SimulationState::Running)))
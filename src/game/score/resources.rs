@@ -1,23 +1,326 @@
+use std::{fs, path::{Path, PathBuf}};
+
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::config::resources::Difficulty;
+use crate::persistence::data_file;
+
+pub const HIGH_SCORES_FILE: &str = "highscores.ron";
+pub const HIGH_SCORES_VERSION: u32 = 2;
+pub const MAX_HIGH_SCORES: usize = 10;
+pub const PLAYER_NAME_FILE: &str = "player_name.txt";
+pub const DEFAULT_PLAYER_NAME: &str = "Player 1";
+pub const MAX_PLAYER_NAME_LENGTH: usize = 12;
 
-#[derive(Resource)]
+#[derive(Resource, Default)]
 pub struct Score {
     pub value: u32
 }
 
-impl Default for Score {
-    fn default() -> Score {
-        Score { value: 0 }
-    }
+/// Outcome of the last finished run, shown on the game over screen.
+/// A score that qualifies for the leaderboard waits for a name before it gets a rank.
+#[derive(Resource, Debug, Default)]
+pub struct FinalScore {
+    pub value: u32,
+    pub difficulty: Difficulty,
+    pub rank: Option<usize>,
+    pub awaiting_name: bool
 }
 
+/// Name used for the last high score, offered again the next time one is entered.
 #[derive(Resource, Debug)]
+pub struct PlayerName {
+    pub value: String
+}
+
+impl Default for PlayerName {
+    fn default() -> PlayerName {
+        PlayerName { value: DEFAULT_PLAYER_NAME.to_string() }
+    }
+}
+
+impl PlayerName {
+    pub fn path() -> PathBuf {
+        data_file(PLAYER_NAME_FILE)
+    }
+
+    pub fn is_allowed_character(character: char) -> bool {
+        character.is_ascii_alphanumeric() || character == ' ' || character == '-' || character == '_'
+    }
+
+    pub fn validate(name: &str) -> Result<String, String> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err("Name cannot be empty".to_string());
+        }
+        if name.chars().count() > MAX_PLAYER_NAME_LENGTH {
+            return Err(format!("Name can be at most {} characters", MAX_PLAYER_NAME_LENGTH));
+        }
+        if !name.chars().all(PlayerName::is_allowed_character) {
+            return Err("Use letters, digits, spaces, - and _".to_string());
+        }
+        Ok(name.to_string())
+    }
+
+    pub fn load(path: &Path) -> PlayerName {
+        fs::read_to_string(path).ok()
+            .and_then(|contents| PlayerName::validate(&contents).ok())
+            .map(|value| PlayerName { value })
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)
+                .map_err(|error| format!("could not create {}: {}", directory.display(), error))?;
+        }
+        fs::write(path, &self.value)
+            .map_err(|error| format!("could not write {}: {}", path.display(), error))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct HighScore {
+    pub name: String,
+    pub score: u32,
+    pub difficulty: Difficulty
+}
+
+/// Leaderboards for every difficulty, kept sorted from best to worst and capped at
+/// `MAX_HIGH_SCORES` entries per difficulty.
+#[derive(Resource, Debug, Default)]
 pub struct HighScores {
-    pub scores: Vec<(String, u32)>
+    pub scores: Vec<HighScore>
+}
+
+#[derive(Serialize, Deserialize)]
+struct HighScoresFile {
+    version: u32,
+    scores: Vec<HighScore>
+}
+
+#[derive(Deserialize)]
+struct HighScoresFileVersion {
+    version: u32
+}
+
+/// Version 1 had a single leaderboard; its entries are carried over as Normal difficulty.
+#[derive(Deserialize)]
+struct HighScoresFileV1 {
+    scores: Vec<(String, u32)>
+}
+
+impl HighScores {
+    pub fn path() -> PathBuf {
+        data_file(HIGH_SCORES_FILE)
+    }
+
+    pub fn leaderboard(&self, difficulty: Difficulty) -> impl Iterator<Item = &HighScore> {
+        self.scores.iter().filter(move |high_score| high_score.difficulty == difficulty)
+    }
+
+    pub fn qualifies(&self, score: u32, difficulty: Difficulty) -> bool {
+        self.leaderboard(difficulty).count() < MAX_HIGH_SCORES
+            || self.leaderboard(difficulty).any(|high_score| score > high_score.score)
+    }
+
+    /// Inserts the score in rank order and returns its zero-based rank on the difficulty's
+    /// leaderboard, or `None` if it did not make the cut.
+    pub fn add(&mut self, name: String, score: u32, difficulty: Difficulty) -> Option<usize> {
+        let position = self.scores.iter().position(|high_score| score > high_score.score).unwrap_or(self.scores.len());
+        let rank = self.scores[..position].iter().filter(|high_score| high_score.difficulty == difficulty).count();
+        if rank >= MAX_HIGH_SCORES { return None; }
+
+        self.scores.insert(position, HighScore { name, score, difficulty });
+        if let Some((dropped, _)) = self.scores.iter().enumerate()
+            .filter(|(_, high_score)| high_score.difficulty == difficulty)
+            .nth(MAX_HIGH_SCORES) {
+            self.scores.remove(dropped);
+        }
+        Some(rank)
+    }
+
+    /// Reads the leaderboard from disk. A missing file is an empty leaderboard; an unreadable
+    /// one is moved aside to `<file>.corrupt` so the next save does not destroy it.
+    pub fn load(path: &Path) -> HighScores {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(_) => return HighScores::default()
+        };
+
+        let scores = ron::from_str::<HighScoresFileVersion>(&contents).and_then(|file| match file.version {
+            1 => ron::from_str::<HighScoresFileV1>(&contents).map(|file| Some(file.scores.into_iter()
+                .map(|(name, score)| HighScore { name, score, difficulty: Difficulty::Normal })
+                .collect::<Vec<_>>())),
+            HIGH_SCORES_VERSION => ron::from_str::<HighScoresFile>(&contents).map(|file| Some(file.scores)),
+            _ => Ok(None)
+        });
+
+        match scores {
+            Ok(Some(scores)) => {
+                let mut high_scores = HighScores::default();
+                for high_score in scores {
+                    high_scores.add(high_score.name, high_score.score, high_score.difficulty);
+                }
+                high_scores
+            }
+            Ok(None) => {
+                println!("High scores in {} have an unsupported version", path.display());
+                HighScores::set_aside(path);
+                HighScores::default()
+            }
+            Err(error) => {
+                println!("High scores in {} are corrupt: {}", path.display(), error);
+                HighScores::set_aside(path);
+                HighScores::default()
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)
+                .map_err(|error| format!("could not create {}: {}", directory.display(), error))?;
+        }
+        let file = HighScoresFile { version: HIGH_SCORES_VERSION, scores: self.scores.clone() };
+        let contents = ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default())
+            .map_err(|error| format!("could not serialize high scores: {}", error))?;
+        fs::write(path, contents)
+            .map_err(|error| format!("could not write {}: {}", path.display(), error))
+    }
+
+    fn set_aside(path: &Path) {
+        let backup = path.with_extension("ron.corrupt");
+        if let Err(error) = fs::rename(path, &backup) {
+            println!("Could not move {} aside: {}", path.display(), error);
+        }
+    }
 }
 
-impl Default for HighScores {
-    fn default() -> HighScores {
-        HighScores { scores: Vec::new() }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A file of its own in the temp directory, so tests running in parallel do not share one.
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("bevy-ball-game-{}-{}", std::process::id(), name))
+    }
+
+    fn scores(high_scores: &HighScores) -> Vec<u32> {
+        high_scores.scores.iter().map(|high_score| high_score.score).collect()
+    }
+
+    #[test]
+    fn keeps_scores_sorted_and_reports_their_rank() {
+        let mut high_scores = HighScores::default();
+
+        assert_eq!(high_scores.add("A".to_string(), 10, Difficulty::Normal), Some(0));
+        assert_eq!(high_scores.add("B".to_string(), 30, Difficulty::Normal), Some(0));
+        assert_eq!(high_scores.add("C".to_string(), 20, Difficulty::Normal), Some(1));
+        assert_eq!(scores(&high_scores), vec![30, 20, 10]);
+    }
+
+    #[test]
+    fn evicts_the_lowest_score_once_full() {
+        let mut high_scores = HighScores::default();
+        for score in 1..=MAX_HIGH_SCORES as u32 {
+            high_scores.add(format!("P{}", score), score * 10, Difficulty::Normal);
+        }
+
+        assert!(!high_scores.qualifies(10, Difficulty::Normal));
+        assert_eq!(high_scores.add("Late".to_string(), 5, Difficulty::Normal), None);
+
+        assert!(high_scores.qualifies(15, Difficulty::Normal));
+        assert_eq!(high_scores.add("New".to_string(), 15, Difficulty::Normal), Some(MAX_HIGH_SCORES - 1));
+        assert_eq!(high_scores.scores.len(), MAX_HIGH_SCORES);
+        assert_eq!(high_scores.scores.last().map(|high_score| high_score.score), Some(15));
+    }
+
+    #[test]
+    fn saves_and_loads_the_leaderboard() {
+        let path = temp_path("round-trip.ron");
+        let mut high_scores = HighScores::default();
+        high_scores.add("A".to_string(), 10, Difficulty::Normal);
+        high_scores.add("B".to_string(), 20, Difficulty::Normal);
+
+        high_scores.save(&path).unwrap();
+        let loaded = HighScores::load(&path);
+        fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.scores, high_scores.scores);
+    }
+
+    #[test]
+    fn missing_file_is_an_empty_leaderboard() {
+        assert!(HighScores::load(&temp_path("missing.ron")).scores.is_empty());
+    }
+
+    #[test]
+    fn keeps_a_leaderboard_per_difficulty() {
+        let mut high_scores = HighScores::default();
+        for score in 1..=MAX_HIGH_SCORES as u32 {
+            high_scores.add(format!("P{}", score), score * 10, Difficulty::Hard);
+        }
+
+        assert!(high_scores.qualifies(1, Difficulty::Easy));
+        assert_eq!(high_scores.add("Easy".to_string(), 1, Difficulty::Easy), Some(0));
+        assert_eq!(high_scores.add("Hard".to_string(), 55, Difficulty::Hard), Some(5));
+        assert_eq!(high_scores.leaderboard(Difficulty::Hard).count(), MAX_HIGH_SCORES);
+        assert_eq!(high_scores.leaderboard(Difficulty::Easy).count(), 1);
+    }
+
+    #[test]
+    fn migrates_a_version_1_leaderboard_to_normal() {
+        let path = temp_path("v1.ron");
+        fs::write(&path, r#"(version: 1, scores: [("A", 10), ("B", 30)])"#).unwrap();
+        let loaded = HighScores::load(&path);
+        fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.scores, vec![
+            HighScore { name: "B".to_string(), score: 30, difficulty: Difficulty::Normal },
+            HighScore { name: "A".to_string(), score: 10, difficulty: Difficulty::Normal }
+        ]);
+    }
+
+    #[test]
+    fn sets_aside_files_it_cannot_read() {
+        for (name, contents) in [("corrupt.ron", "not ron"), ("future.ron", "(version: 99, scores: [])")] {
+            let path = temp_path(name);
+            let backup = path.with_extension("ron.corrupt");
+            fs::write(&path, contents).unwrap();
+
+            assert!(HighScores::load(&path).scores.is_empty());
+            assert!(!path.exists());
+            assert!(backup.exists());
+            fs::remove_file(&backup).ok();
+        }
+    }
+
+    #[test]
+    fn trims_and_accepts_valid_names() {
+        assert_eq!(PlayerName::validate("  Ada_Lovelace "), Ok("Ada_Lovelace".to_string()));
+        assert_eq!(PlayerName::validate("Player-1"), Ok("Player-1".to_string()));
+    }
+
+    #[test]
+    fn rejects_empty_long_and_odd_names() {
+        assert!(PlayerName::validate("   ").is_err());
+        assert!(PlayerName::validate(&"x".repeat(MAX_PLAYER_NAME_LENGTH + 1)).is_err());
+        assert!(PlayerName::validate(&"x".repeat(MAX_PLAYER_NAME_LENGTH)).is_ok());
+        assert!(PlayerName::validate("semi;colon").is_err());
+        assert!(PlayerName::validate("émile").is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn falls_back_to_the_default_name() {
+        let path = temp_path("player_name.txt");
+        fs::write(&path, "\n").unwrap();
+        let loaded = PlayerName::load(&path);
+        fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.value, DEFAULT_PLAYER_NAME);
+        assert_eq!(PlayerName::load(&temp_path("missing.txt")).value, DEFAULT_PLAYER_NAME);
+    }
+}
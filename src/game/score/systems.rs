@@ -1,7 +1,17 @@
 use bevy::prelude::*;
 
 use super::resources::*;
+use crate::args::LaunchArgs;
 use crate::events::GameOver;
+use crate::game::config::resources::Difficulty;
+use crate::replay::resources::ReplayPlayback;
+
+pub fn load_high_scores(
+    mut commands: Commands
+) {
+    commands.insert_resource(HighScores::load(&HighScores::path()));
+    commands.insert_resource(PlayerName::load(&PlayerName::path()));
+}
 
 pub fn insert_score(
     mut commands: Commands
@@ -17,16 +27,29 @@ pub fn remove_score(
 
 pub fn update_score(score: Res<Score>) {
     if score.is_changed() {
-        println!("Score: {}", score.value.to_string());
+        println!("Score: {}", score.value);
     }
 }
 
+/// Keeps the final score, asking for a name when it makes the leaderboard. Headless runs and replays
+/// have no one at the keyboard, so they never make it.
 pub fn update_highscores(
+    mut commands: Commands,
     mut game_over_event_reader: EventReader<GameOver>,
-    mut high_scores: ResMut<HighScores>
+    high_scores: Res<HighScores>,
+    difficulty: Res<Difficulty>,
+    launch_args: Res<LaunchArgs>,
+    replay_playback: Option<Res<ReplayPlayback>>
 ) {
+    let is_ranked = !launch_args.headless && replay_playback.is_none();
+
     for event in game_over_event_reader.read() {
-        high_scores.scores.push(("Player 1".to_string(), event.score))
+        commands.insert_resource(FinalScore {
+            value: event.score,
+            difficulty: *difficulty,
+            rank: None,
+            awaiting_name: is_ranked && high_scores.qualifies(event.score, *difficulty)
+        });
     }
 }
 
@@ -36,4 +59,4 @@ pub fn high_scores_updated(
     if high_scores.is_changed() {
         println!("High scores: {:?}", high_scores);
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,50 @@
 use bevy::prelude::*;
 
+/// A collectible on the field, holding what its `StarKind` is worth when collected.
 #[derive(Component)]
-pub struct Star {}
\ No newline at end of file
+pub struct Star {
+    pub score: u32,
+    pub sound: String
+}
+
+/// Time a star has left on the field. Stars without one never expire.
+#[derive(Component)]
+pub struct StarLifetime {
+    pub timer: Timer
+}
+
+impl StarLifetime {
+    pub fn from_seconds(seconds: f32) -> StarLifetime {
+        StarLifetime { timer: Timer::from_seconds(seconds, TimerMode::Once) }
+    }
+
+    /// Share of `max_bonus` earned by collecting the star now, rounded to whole points.
+    pub fn bonus(&self, max_bonus: u32) -> u32 {
+        (max_bonus as f32 * self.timer.percent_left()).round() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn bonus_shrinks_from_full_to_nothing() {
+        let mut lifetime = StarLifetime::from_seconds(4.0);
+        assert_eq!(lifetime.bonus(10), 10);
+
+        lifetime.timer.tick(Duration::from_secs(1));
+        assert_eq!(lifetime.bonus(10), 8);
+
+        lifetime.timer.tick(Duration::from_secs(3));
+        assert_eq!(lifetime.bonus(10), 0);
+    }
+
+    #[test]
+    fn zero_max_bonus_gives_nothing() {
+        let lifetime = StarLifetime::from_seconds(4.0);
+        assert_eq!(lifetime.bonus(0), 0);
+    }
+}
@@ -4,26 +4,28 @@ pub mod components;
 mod resources;
 mod systems;
 
-use resources::StarSpawnTimer;
 use systems::*;
 
 use crate::AppState;
 
-use super::SimulationState;
+use super::{RngSystemSet, SimulationState};
+use super::config::ConfigSystemSet;
 
-pub const STAR_SIZE: f32 = 30.0;
-pub const NUMBER_OF_STARS: usize = 10;
+/// Blinks per second of a star about to expire.
+pub const STAR_BLINK_RATE: f32 = 4.0;
 
 pub struct StarPlugin;
 
 impl Plugin for StarPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<StarSpawnTimer>()
-            .add_systems(OnEnter(AppState::Game), spawn_stars)
-            .add_systems(Update, 
-                (tick_star_spawn_timer,  spawn_stars_over_time)
+        app.add_systems(OnEnter(AppState::Game), (insert_star_spawn_timer.after(ConfigSystemSet), spawn_stars.in_set(RngSystemSet::Star)))
+            .add_systems(FixedUpdate, 
+                (tick_star_spawn_timer, expire_stars, spawn_stars_over_time)
+                    .chain()
+                    .in_set(RngSystemSet::Star)
                     .run_if(in_state(AppState::Game)).run_if(in_state(SimulationState::Running))
             )
-            .add_systems(OnExit(AppState::Game), despawn_stars);
+            .add_systems(Update, fade_stars.run_if(in_state(AppState::Game)))
+            .add_systems(OnExit(AppState::Game), (despawn_stars, remove_star_spawn_timer));
     }
 }
\ No newline at end of file
@@ -1,14 +1,12 @@
 use bevy::prelude::*;
 
-pub const STAR_SPAWN_TIME: f32 = 1.0;
-
 #[derive(Resource)]
 pub struct StarSpawnTimer {
     pub timer: Timer
 }
 
-impl Default for StarSpawnTimer {
-    fn default() -> StarSpawnTimer {
-        StarSpawnTimer { timer: Timer::from_seconds(STAR_SPAWN_TIME, TimerMode::Repeating) }
+impl StarSpawnTimer {
+    pub fn from_seconds(seconds: f32) -> StarSpawnTimer {
+        StarSpawnTimer { timer: Timer::from_seconds(seconds, TimerMode::Repeating) }
     }
 }
\ No newline at end of file
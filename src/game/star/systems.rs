@@ -1,30 +1,79 @@
-use bevy::{prelude::*, window::PrimaryWindow};
+use std::time::Duration;
 
-use rand::random;
-use super::{NUMBER_OF_STARS, components::Star, resources::StarSpawnTimer};
+use bevy::{prelude::*, ecs::system::SystemParam};
 
-pub fn spawn_stars(
-    mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>
-) {
-    let window = window_query.get_single().unwrap();
+use crate::game::config::resources::GameConfig;
+use crate::game::director::resources::DifficultyDirector;
+use crate::game::collision::components::Collider;
+use crate::game::collision::resources::{CollisionLayer, SpatialHash};
+use crate::game::helpers::helpers::{clearance_from, pick_spawn_point};
+use crate::game::resources::{GameRng, Playfield};
+use super::STAR_BLINK_RATE;
+use super::{components::{Star, StarLifetime}, resources::StarSpawnTimer};
+
+/// Everything needed to place and spawn stars where they do not overlap each other or an enemy.
+/// Stars and enemies already on the field are looked up in the spatial hash.
+#[derive(SystemParam)]
+pub struct StarSpawner<'w, 's> {
+    commands: Commands<'w, 's>,
+    asset_server: Res<'w, AssetServer>,
+    game_config: Res<'w, GameConfig>,
+    playfield: Res<'w, Playfield>,
+    game_rng: ResMut<'w, GameRng>,
+    spatial_hash: Res<'w, SpatialHash>,
+    star_query: Query<'w, 's, (), With<Star>>
+}
+
+impl StarSpawner<'_, '_> {
+    /// Spawns up to `count` stars without going over `max_live`. Each lands fully inside the arena,
+    /// at least `spacing` from every other star and clear of every enemy.
+    fn spawn(&mut self, count: usize) {
+        let star_config = &self.game_config.star;
+        let count = count.min(star_config.max_live.saturating_sub(self.star_query.iter().count()));
+        let (playfield, spatial_hash) = (&self.playfield, &self.spatial_hash);
+        let mut placed = Vec::new();
 
-    for _ in 0..NUMBER_OF_STARS {
-        let (random_x, random_y) = (random::<f32>() * window.width(), random::<f32>() * window.height());
-        commands.spawn(
-            (
-                SpriteBundle {
-                    transform: Transform::from_xyz(random_x, random_y, 0.0),
-                    texture: asset_server.load("sprites/star.png"),
-                    ..default()
-                },
-                Star {}
-            )
-        );
+        for _ in 0..count {
+            let clearance = |candidate: Vec2| {
+                let stars = spatial_hash.query(candidate, star_config.spacing, CollisionLayer::Star)
+                    .map(|entry| entry.position)
+                    .chain(placed.iter().copied());
+                let enemies = spatial_hash.query(candidate, star_config.size / 2.0, CollisionLayer::Enemy)
+                    .map(|entry| candidate.distance(entry.position) - entry.collider.bounding_radius() - star_config.size / 2.0);
+                enemies.fold(clearance_from(candidate, stars, star_config.spacing), f32::min)
+            };
+            let position = pick_spawn_point(&mut self.game_rng, clearance, |game_rng| {
+                playfield.inner_point_at(game_rng.random_fraction(), star_config.size)
+            });
+            placed.push(position);
+
+            let Some(kind) = star_config.pick_kind(&mut self.game_rng.rng) else { return; };
+            let mut star = self.commands.spawn(
+                (
+                    SpriteBundle {
+                        transform: Transform::from_translation(position.extend(0.0)),
+                        texture: self.asset_server.load(&kind.sprite),
+                        sprite: Sprite { color: kind.color(), custom_size: Some(Vec2::splat(star_config.size)), ..default() },
+                        ..default()
+                    },
+                    Star { score: kind.score, sound: kind.sound.clone() },
+                    Collider::circle(star_config.size / 2.0, CollisionLayer::Star)
+                )
+            );
+            if star_config.lifetime > 0.0 {
+                star.insert(StarLifetime::from_seconds(star_config.lifetime));
+            }
+        }
     }
 }
 
+pub fn spawn_stars(
+    mut spawner: StarSpawner
+) {
+    let count = spawner.game_config.star.count;
+    spawner.spawn(count);
+}
+
 pub fn despawn_stars(
     mut commands: Commands,
     star_query: Query<Entity, With<Star>>
@@ -34,31 +83,65 @@ pub fn despawn_stars(
     }
 }
 
+pub fn insert_star_spawn_timer(
+    mut commands: Commands,
+    game_config: Res<GameConfig>
+) {
+    commands.insert_resource(StarSpawnTimer::from_seconds(game_config.star.spawn_time));
+}
+
+pub fn remove_star_spawn_timer(
+    mut commands: Commands
+) {
+    commands.remove_resource::<StarSpawnTimer>();
+}
+
 pub fn tick_star_spawn_timer(
     mut star_spawn_timer: ResMut<StarSpawnTimer>,
+    director: Res<DifficultyDirector>,
+    game_config: Res<GameConfig>,
     time: Res<Time>
 ) {
+    star_spawn_timer.timer.set_duration(Duration::from_secs_f32(director.star_spawn_time(&game_config)));
     star_spawn_timer.timer.tick(time.delta());
 }
 
 pub fn spawn_stars_over_time(
-    mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    mut spawner: StarSpawner,
     star_spawn_timer: Res<StarSpawnTimer>
 ) {
     if star_spawn_timer.timer.finished() {
-        let window =window_query.get_single().unwrap();
-        let (random_x, random_y) = (random::<f32>() * window.width(), random::<f32>() * window.height());
-        commands.spawn(
-            (
-                SpriteBundle {
-                    transform: Transform::from_xyz(random_x, random_y, 0.0),
-                    texture: asset_server.load("sprites/star.png"),
-                    ..default()
-                },
-                Star {}
-            )
-        );
+        spawner.spawn(1);
     }
-}
\ No newline at end of file
+}
+
+pub fn expire_stars(
+    mut commands: Commands,
+    mut star_query: Query<(Entity, &mut StarLifetime)>,
+    time: Res<Time>
+) {
+    for (star_entity, mut lifetime) in star_query.iter_mut() {
+        if lifetime.timer.tick(time.delta()).finished() {
+            commands.entity(star_entity).despawn();
+        }
+    }
+}
+
+/// Blinks stars close to expiring while fading them out.
+pub fn fade_stars(
+    mut star_query: Query<(&mut Sprite, &StarLifetime)>,
+    game_config: Res<GameConfig>
+) {
+    let fade_time = game_config.star.fade_time;
+
+    for (mut sprite, lifetime) in star_query.iter_mut() {
+        let remaining = lifetime.timer.remaining_secs();
+        let alpha = if remaining >= fade_time {
+            1.0
+        } else {
+            let is_dimmed = (remaining * STAR_BLINK_RATE * 2.0) as u32 % 2 == 1;
+            remaining / fade_time * if is_dimmed { 0.3 } else { 1.0 }
+        };
+        sprite.color.set_a(alpha);
+    }
+}
@@ -1,6 +1,25 @@
 use bevy::prelude::*;
+use rand::random;
 
 use super::SimulationState;
+use super::resources::{GameRng, GameSeed};
+
+/// Drops time left over from the menus so every run starts its fixed steps from the same point.
+pub fn reset_fixed_time(
+    mut fixed_time: ResMut<Time<Fixed>>
+) {
+    let timestep = fixed_time.timestep();
+    *fixed_time = Time::<Fixed>::from_duration(timestep);
+}
+
+/// Replays skip this; `restore_replay_conditions` seeds the rng with the recorded seed instead.
+pub fn seed_game_rng(
+    game_seed: Res<GameSeed>,
+    mut game_rng: ResMut<GameRng>
+) {
+    *game_rng = GameRng::from_seed(game_seed.value.unwrap_or_else(random));
+    println!("Game seed: {}", game_rng.seed);
+}
 
 pub fn pause_simulation(
     mut next_simulation_state: ResMut<NextState<SimulationState>>
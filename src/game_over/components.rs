@@ -0,0 +1,25 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct GameOverMenu {}
+
+#[derive(Component)]
+pub struct PlayAgainButton {}
+
+#[derive(Component)]
+pub struct MainMenuButton {}
+
+#[derive(Component)]
+pub struct QuitButton {}
+
+#[derive(Component)]
+pub struct RankText {}
+
+#[derive(Component)]
+pub struct NameEntry {}
+
+#[derive(Component)]
+pub struct NameText {}
+
+#[derive(Component)]
+pub struct NameHintText {}
@@ -0,0 +1,32 @@
+use bevy::{prelude::*, app::AppExit};
+
+use crate::AppState;
+
+use self::resources::NameInput;
+use self::systems::layout::{spawn_game_over_menu, despawn_game_over_menu};
+use self::systems::interactions::{interact_with_play_again_button, interact_with_main_menu_button, interact_with_quit_button};
+use self::systems::name_entry::{start_name_entry, stop_name_entry, type_name, submit_name, commit_pending_name, refresh_name_entry, refresh_rank_text};
+
+mod components;
+pub mod resources;
+mod systems;
+
+pub struct GameOverPlugin;
+
+impl Plugin for GameOverPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::GameOver), (spawn_game_over_menu, start_name_entry));
+        app.add_systems(Update, (
+            interact_with_play_again_button, interact_with_main_menu_button, interact_with_quit_button,
+            refresh_rank_text
+        ).run_if(in_state(AppState::GameOver)));
+        app.add_systems(Update, (type_name, submit_name, refresh_name_entry)
+            .chain()
+            .run_if(in_state(AppState::GameOver))
+            .run_if(resource_exists::<NameInput>()));
+        app.add_systems(Last, commit_pending_name
+            .run_if(in_state(AppState::GameOver))
+            .run_if(on_event::<AppExit>()));
+        app.add_systems(OnExit(AppState::GameOver), (despawn_game_over_menu, commit_pending_name.before(stop_name_entry), stop_name_entry));
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+pub const NAME_ENTRY_HINT: &str = "Type your name and press Enter";
+
+/// Name being typed for a new high score. Only present while the entry field is shown.
+#[derive(Resource, Debug, Default)]
+pub struct NameInput {
+    pub value: String,
+    pub error: Option<String>
+}
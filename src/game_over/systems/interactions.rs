@@ -0,0 +1,56 @@
+use bevy::app::AppExit;
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::game_over::components::{PlayAgainButton, MainMenuButton, QuitButton};
+use crate::main_menu::styles::{NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR, PRESSED_BUTTON_COLOR};
+
+type ButtonInteraction<'a> = (&'a Interaction, &'a mut BackgroundColor);
+
+pub fn interact_with_play_again_button(
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<PlayAgainButton>)>,
+    mut app_state_next_state: ResMut<NextState<AppState>>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::Game)
+            }
+            Interaction::Hovered => { *background_color = HOVERED_BUTTON_COLOR.into(); }
+            Interaction::None => { *background_color = NORMAL_BUTTON_COLOR.into() }
+        }
+    }
+}
+
+pub fn interact_with_main_menu_button(
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<MainMenuButton>)>,
+    mut app_state_next_state: ResMut<NextState<AppState>>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::MainMenu)
+            }
+            Interaction::Hovered => { *background_color = HOVERED_BUTTON_COLOR.into(); }
+            Interaction::None => { *background_color = NORMAL_BUTTON_COLOR.into() }
+        }
+    }
+}
+
+pub fn interact_with_quit_button(
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<QuitButton>)>,
+    mut app_exit_event_writer: EventWriter<AppExit>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                app_exit_event_writer.send(AppExit);
+            }
+            Interaction::Hovered => { *background_color = HOVERED_BUTTON_COLOR.into(); }
+            Interaction::None => { *background_color = NORMAL_BUTTON_COLOR.into() }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+
+use crate::game::score::resources::{FinalScore, PlayerName};
+use crate::game_over::components::{GameOverMenu, PlayAgainButton, MainMenuButton, QuitButton, RankText, NameEntry, NameText, NameHintText};
+use crate::game_over::resources::NAME_ENTRY_HINT;
+use crate::main_menu::styles::*;
+
+pub fn spawn_game_over_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    final_score: Res<FinalScore>,
+    player_name: Res<PlayerName>
+) {
+    build_game_over_menu(&mut commands, &asset_server, &final_score, &player_name);
+}
+
+pub fn despawn_game_over_menu(
+    mut commands: Commands,
+    game_over_menu_query: Query<Entity, With<GameOverMenu>>
+) {
+    if let Ok(game_over_menu_entity) = game_over_menu_query.get_single() {
+        commands.entity(game_over_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn rank_text(final_score: &FinalScore) -> String {
+    if final_score.awaiting_name {
+        return "New high score!".to_string();
+    }
+    let difficulty = final_score.difficulty.name();
+    match final_score.rank {
+        Some(0) => format!("New best score on {}!", difficulty),
+        Some(rank) => format!("Ranked #{} in {} high scores", rank + 1, difficulty),
+        None => format!("Not in the {} high scores this time", difficulty)
+    }
+}
+
+pub fn build_game_over_menu(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    final_score: &FinalScore,
+    player_name: &PlayerName
+) -> Entity {
+    let game_over_menu_entity = commands
+        .spawn(
+            (NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                ..default()
+            },
+            GameOverMenu {}
+        )
+    )
+    .with_children(|parent| {
+        parent.spawn(draw_text(asset_server, "Game Over".to_string(), 64.0));
+        parent.spawn(draw_text(asset_server, format!("Final score: {}", final_score.value), 40.0));
+        parent.spawn((draw_text(asset_server, rank_text(final_score), 24.0), RankText {}));
+        if final_score.awaiting_name {
+            parent.spawn(
+                (NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(4.0),
+                        margin: UiRect::vertical(Val::Px(8.0)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                NameEntry {}
+            )
+            ).with_children(|parent| {
+                parent.spawn((draw_text(asset_server, format!("{}_", player_name.value), 32.0), NameText {}));
+                parent.spawn((draw_text(asset_server, NAME_ENTRY_HINT.to_string(), 20.0), NameHintText {}));
+            });
+        }
+        parent.spawn(
+            (
+                ButtonBundle {
+                    style: BUTTON_STYLE,
+                    background_color: NORMAL_BUTTON_COLOR.into(),
+                    ..Default::default()
+                },
+                PlayAgainButton {}
+            )
+        ).with_children(|parent| {
+            parent.spawn(draw_text(asset_server, "Play Again".to_string(), 32.0));
+        });
+        parent.spawn(
+            (
+                ButtonBundle {
+                    style: BUTTON_STYLE,
+                    background_color: NORMAL_BUTTON_COLOR.into(),
+                    ..Default::default()
+                },
+                MainMenuButton {}
+            )
+        ).with_children(|parent| {
+            parent.spawn(draw_text(asset_server, "Main Menu".to_string(), 32.0));
+        });
+        parent.spawn(
+            (
+                ButtonBundle {
+                    style: BUTTON_STYLE,
+                    background_color: NORMAL_BUTTON_COLOR.into(),
+                    ..Default::default()
+                },
+                QuitButton {}
+            )
+        ).with_children(|parent| {
+            parent.spawn(draw_text(asset_server, "Quit".to_string(), 32.0));
+        });
+    })
+    .id();
+
+    game_over_menu_entity
+}
@@ -0,0 +1,3 @@
+pub mod interactions;
+pub mod layout;
+pub mod name_entry;
\ No newline at end of file
@@ -0,0 +1,125 @@
+use bevy::{prelude::*, window::ReceivedCharacter};
+
+use crate::game::score::resources::{FinalScore, HighScores, PlayerName, MAX_PLAYER_NAME_LENGTH};
+use crate::game_over::components::{NameEntry, NameText, NameHintText, RankText};
+use crate::game_over::resources::{NameInput, NAME_ENTRY_HINT};
+use crate::game_over::systems::layout::rank_text;
+
+pub fn start_name_entry(
+    mut commands: Commands,
+    final_score: Res<FinalScore>,
+    player_name: Res<PlayerName>
+) {
+    if final_score.awaiting_name {
+        commands.insert_resource(NameInput { value: player_name.value.clone(), error: None });
+    }
+}
+
+pub fn stop_name_entry(
+    mut commands: Commands
+) {
+    commands.remove_resource::<NameInput>();
+}
+
+pub fn type_name(
+    mut received_character_event_reader: EventReader<ReceivedCharacter>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mut name_input: ResMut<NameInput>
+) {
+    for event in received_character_event_reader.read() {
+        if PlayerName::is_allowed_character(event.char) && name_input.value.chars().count() < MAX_PLAYER_NAME_LENGTH {
+            name_input.value.push(event.char);
+            name_input.error = None;
+        }
+    }
+    if keyboard_input.just_pressed(KeyCode::Back) {
+        name_input.value.pop();
+        name_input.error = None;
+    }
+}
+
+pub fn submit_name(
+    mut commands: Commands,
+    keyboard_input: Res<Input<KeyCode>>,
+    mut name_input: ResMut<NameInput>,
+    mut final_score: ResMut<FinalScore>,
+    mut high_scores: ResMut<HighScores>,
+    mut player_name: ResMut<PlayerName>,
+    name_entry_query: Query<Entity, With<NameEntry>>
+) {
+    if !keyboard_input.just_pressed(KeyCode::Return) { return; }
+
+    let name = match PlayerName::validate(&name_input.value) {
+        Ok(name) => name,
+        Err(error) => {
+            name_input.error = Some(error);
+            return;
+        }
+    };
+
+    record_high_score(name, &mut final_score, &mut high_scores, &mut player_name);
+
+    if let Ok(name_entry_entity) = name_entry_query.get_single() {
+        commands.entity(name_entry_entity).despawn_recursive();
+    }
+    commands.remove_resource::<NameInput>();
+}
+
+/// Files a score still waiting for a name when the Game Over screen is left without pressing Enter,
+/// under the typed name if it is valid and the last one used otherwise.
+pub fn commit_pending_name(
+    name_input: Option<Res<NameInput>>,
+    mut final_score: ResMut<FinalScore>,
+    mut high_scores: ResMut<HighScores>,
+    mut player_name: ResMut<PlayerName>
+) {
+    let Some(name_input) = name_input else { return; };
+    if !final_score.awaiting_name { return; }
+
+    let name = PlayerName::validate(&name_input.value).unwrap_or_else(|_| player_name.value.clone());
+    record_high_score(name, &mut final_score, &mut high_scores, &mut player_name);
+}
+
+fn record_high_score(
+    name: String,
+    final_score: &mut FinalScore,
+    high_scores: &mut HighScores,
+    player_name: &mut PlayerName
+) {
+    final_score.rank = high_scores.add(name.clone(), final_score.value, final_score.difficulty);
+    final_score.awaiting_name = false;
+    if let Err(error) = high_scores.save(&HighScores::path()) {
+        println!("High scores not saved: {}", error);
+    }
+
+    player_name.value = name;
+    if let Err(error) = player_name.save(&PlayerName::path()) {
+        println!("Player name not saved: {}", error);
+    }
+}
+
+pub fn refresh_name_entry(
+    name_input: Res<NameInput>,
+    mut name_text_query: Query<&mut Text, (With<NameText>, Without<NameHintText>)>,
+    mut hint_text_query: Query<&mut Text, (With<NameHintText>, Without<NameText>)>
+) {
+    if !name_input.is_changed() { return; }
+
+    if let Ok(mut text) = name_text_query.get_single_mut() {
+        text.sections[0].value = format!("{}_", name_input.value);
+    }
+    if let Ok(mut text) = hint_text_query.get_single_mut() {
+        text.sections[0].value = name_input.error.clone().unwrap_or(NAME_ENTRY_HINT.to_string());
+    }
+}
+
+pub fn refresh_rank_text(
+    final_score: Res<FinalScore>,
+    mut rank_text_query: Query<&mut Text, With<RankText>>
+) {
+    if !final_score.is_changed() { return; }
+
+    if let Ok(mut text) = rank_text_query.get_single_mut() {
+        text.sections[0].value = rank_text(&final_score);
+    }
+}
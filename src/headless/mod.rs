@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, asset::AssetPlugin, input::{InputPlugin, InputSystem}, time::TimeUpdateStrategy, window::ReceivedCharacter};
+
+mod systems;
+
+use systems::*;
+
+use crate::AppState;
+use crate::game::SimulationState;
+use crate::replay::resources::ReplayPlayback;
+
+pub const HEADLESS_FRAME_TIME: f32 = 1.0 / 60.0;
+
+#[derive(Resource)]
+pub struct HeadlessFrames {
+    pub elapsed: u32,
+    pub max: Option<u32>
+}
+
+/// Replaces `DefaultPlugins` with the bare minimum needed to drive `GamePlugin`
+/// without a window, GPU or audio device. Time advances by a fixed step each frame
+/// so batch simulations run as fast as the CPU allows.
+pub struct HeadlessPlugin {
+    pub max_frames: Option<u32>
+}
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), InputPlugin))
+            .init_asset::<Image>()
+            .init_asset::<AudioSource>()
+            .init_asset::<Font>()
+            .add_event::<ReceivedCharacter>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(HEADLESS_FRAME_TIME)))
+            .insert_resource(HeadlessFrames { elapsed: 0, max: self.max_frames })
+            .add_systems(Startup, start_game)
+            .add_systems(PreUpdate, press_resume_key
+                .after(InputSystem)
+                .run_if(in_state(AppState::Game))
+                .run_if(in_state(SimulationState::Paused))
+                .run_if(not(resource_exists::<ReplayPlayback>())))
+            .add_systems(Update, count_frames)
+            .add_systems(OnEnter(AppState::GameOver), exit_on_game_over);
+    }
+}
@@ -0,0 +1,45 @@
+use bevy::{prelude::*, app::AppExit};
+
+use super::HeadlessFrames;
+use crate::AppState;
+use crate::game::SimulationState;
+
+pub fn start_game(
+    mut next_app_state: ResMut<NextState<AppState>>
+) {
+    next_app_state.set(AppState::Game);
+}
+
+/// Resumes the simulation the way a player would, by tapping Space, so the press ends up in recordings.
+/// Skipped while the previous tap's state change is still pending, or it would pause again right away.
+pub fn press_resume_key(
+    mut keyboard_input: ResMut<Input<KeyCode>>,
+    next_simulation_state: Res<NextState<SimulationState>>
+) {
+    if next_simulation_state.0.is_some() { return; }
+
+    keyboard_input.press(KeyCode::Space);
+    keyboard_input.release(KeyCode::Space);
+}
+
+pub fn exit_on_game_over(
+    mut app_exit_event_writer: EventWriter<AppExit>,
+    frames: Res<HeadlessFrames>
+) {
+    println!("Headless run finished after {} frames", frames.elapsed);
+    app_exit_event_writer.send(AppExit);
+}
+
+pub fn count_frames(
+    mut frames: ResMut<HeadlessFrames>,
+    mut app_exit_event_writer: EventWriter<AppExit>
+) {
+    frames.elapsed += 1;
+
+    if let Some(max) = frames.max {
+        if frames.elapsed >= max {
+            println!("Headless run stopped after reaching {} frames", max);
+            app_exit_event_writer.send(AppExit);
+        }
+    }
+}
@@ -1,26 +1,62 @@
 use bevy::prelude::*;
 
+pub mod args;
 pub mod events;
 mod systems;
 mod game;
+mod game_over;
+mod headless;
 mod main_menu;
+mod pause_menu;
+mod persistence;
+mod replay;
 
+use args::LaunchArgs;
 use game::GamePlugin;
+use game::level::resources::GameMode;
+use game::resources::GameSeed;
+use game_over::{GameOverPlugin, resources::NameInput};
+use headless::HeadlessPlugin;
 use main_menu::MainMenuPlugin;
+use pause_menu::PauseMenuPlugin;
+use replay::ReplayPlugin;
 use systems::*;
 
 
 fn main() {
-    App::new()
-    .add_plugins(DefaultPlugins)
+    let launch_args = LaunchArgs::from_env();
+    if launch_args.bench_collisions {
+        game::collision::benchmark::run();
+        return;
+    }
+    build_app(launch_args).run();
+}
+
+fn build_app(launch_args: LaunchArgs) -> App {
+    let mut app = App::new();
+
+    if launch_args.headless {
+        app.add_plugins(HeadlessPlugin { max_frames: launch_args.max_frames });
+    } else {
+        app.add_plugins(DefaultPlugins);
+    }
+
+    if launch_args.levels {
+        app.insert_resource(GameMode::Levels);
+    }
+
+    app
+    .insert_resource(GameSeed { value: launch_args.seed })
+    .insert_resource(launch_args)
     .add_state::<AppState>()
-    .add_plugins((GamePlugin, MainMenuPlugin))
+    .add_plugins((GamePlugin, GameOverPlugin, MainMenuPlugin, PauseMenuPlugin, ReplayPlugin))
     .add_systems(Startup, spawn_camera)
     .add_systems(Update, (
-        transition_to_game_state, transition_to_main_menu_state,
-        exit_game, handle_game_over, 
-    ))
-    .run();
+        (transition_to_game_state, transition_to_main_menu_state, exit_game)
+            .run_if(not(resource_exists::<NameInput>())),
+        handle_game_over, fit_camera_to_playfield
+    ));
+    app
 }
 
 #[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
@@ -29,4 +65,44 @@ pub enum AppState {
     MainMenu,
     Game,
     GameOver
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::AppExit;
+
+    use super::*;
+    use game::score::resources::FinalScore;
+    use replay::resources::Replay;
+
+    /// Far more than a run without input lasts, so a run that never ends fails instead of hanging.
+    const MAX_TEST_FRAMES: u32 = 20_000;
+
+    /// Steps a headless game until it exits on its own after the game is over, and returns the final score.
+    fn run_headless(launch_args: LaunchArgs) -> u32 {
+        let mut app = build_app(launch_args);
+        app.finish();
+        app.cleanup();
+
+        for _ in 0..MAX_TEST_FRAMES {
+            app.update();
+            if !app.world.resource::<Events<AppExit>>().is_empty() { break; }
+        }
+        assert_eq!(app.world.resource::<State<AppState>>().get(), &AppState::GameOver);
+        app.world.resource::<FinalScore>().value
+    }
+
+    #[test]
+    fn a_recorded_run_replays_to_the_same_final_score() {
+        let path = std::env::temp_dir().join(format!("bevy-ball-game-{}-recorded-run.ron", std::process::id()));
+
+        let recorded_score = run_headless(LaunchArgs { headless: true, seed: Some(42), record: Some(path.clone()), ..default() });
+        let replay = Replay::load(&path).unwrap();
+        let replayed_score = run_headless(LaunchArgs { headless: true, replay: Some(path.clone()), ..default() });
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(replay.seed, 42);
+        assert_eq!(replay.final_score, Some(recorded_score));
+        assert_eq!(replayed_score, recorded_score);
+    }
+}
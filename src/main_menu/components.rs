@@ -6,5 +6,20 @@ pub struct MainMenu {}
 #[derive(Component)]
 pub struct PlayButton {}
 
+#[derive(Component)]
+pub struct DifficultyButton {}
+
+#[derive(Component)]
+pub struct DifficultyText {}
+
+#[derive(Component)]
+pub struct GameModeButton {}
+
+#[derive(Component)]
+pub struct GameModeText {}
+
+#[derive(Component)]
+pub struct ReplayButton {}
+
 #[derive(Component)]
 pub struct QuitButton {}
\ No newline at end of file
@@ -2,11 +2,16 @@ use bevy::app::AppExit;
 use bevy::prelude::*;
 
 use crate::AppState;
-use crate::main_menu::components::{PlayButton, QuitButton};
+use crate::game::config::resources::Difficulty;
+use crate::game::level::resources::{GameMode, Levels};
+use crate::main_menu::components::{PlayButton, DifficultyButton, DifficultyText, GameModeButton, GameModeText, ReplayButton, QuitButton};
+use crate::replay::{begin_playback, resources::ReplaySettings};
 use crate::main_menu::styles::{NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR, PRESSED_BUTTON_COLOR};
 
+type ButtonInteraction<'a> = (&'a Interaction, &'a mut BackgroundColor);
+
 pub fn interact_with_play_button(
-    mut button_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<PlayButton>)>,
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<PlayButton>)>,
     mut app_state_next_state: ResMut<NextState<AppState>>
 ) {
     if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
@@ -21,8 +26,75 @@ pub fn interact_with_play_button(
     }
 }
 
+pub fn interact_with_difficulty_button(
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<DifficultyButton>)>,
+    mut text_query: Query<&mut Text, With<DifficultyText>>,
+    mut difficulty: ResMut<Difficulty>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                *difficulty = difficulty.next();
+                println!("Difficulty: {}", difficulty.name());
+
+                if let Ok(mut text) = text_query.get_single_mut() {
+                    text.sections[0].value = difficulty.name().to_string();
+                }
+            }
+            Interaction::Hovered => { *background_color = HOVERED_BUTTON_COLOR.into(); }
+            Interaction::None => { *background_color = NORMAL_BUTTON_COLOR.into() }
+        }
+    }
+}
+
+pub fn interact_with_game_mode_button(
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<GameModeButton>)>,
+    mut text_query: Query<&mut Text, With<GameModeText>>,
+    mut game_mode: ResMut<GameMode>,
+    levels: Res<Levels>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                match *game_mode {
+                    GameMode::Endless if levels.levels.is_empty() => println!("No levels available"),
+                    GameMode::Endless => *game_mode = GameMode::Levels,
+                    GameMode::Levels => *game_mode = GameMode::Endless
+                }
+                println!("Mode: {}", game_mode.name());
+
+                if let Ok(mut text) = text_query.get_single_mut() {
+                    text.sections[0].value = game_mode.name().to_string();
+                }
+            }
+            Interaction::Hovered => { *background_color = HOVERED_BUTTON_COLOR.into(); }
+            Interaction::None => { *background_color = NORMAL_BUTTON_COLOR.into() }
+        }
+    }
+}
+
+pub fn interact_with_replay_button(
+    mut commands: Commands,
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<ReplayButton>)>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+    replay_settings: Res<ReplaySettings>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                begin_playback(&mut commands, &mut app_state_next_state, &replay_settings.record_path);
+            }
+            Interaction::Hovered => { *background_color = HOVERED_BUTTON_COLOR.into(); }
+            Interaction::None => { *background_color = NORMAL_BUTTON_COLOR.into() }
+        }
+    }
+}
+
 pub fn interact_with_quit_button(
-    mut button_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<QuitButton>)>,
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<QuitButton>)>,
     mut app_exit_event_writer: EventWriter<AppExit>
 ) {
     if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
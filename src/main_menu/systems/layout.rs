@@ -1,13 +1,17 @@
 use bevy::prelude::*;
 
-use crate::main_menu::components::{MainMenu, PlayButton, QuitButton};
+use crate::game::config::resources::Difficulty;
+use crate::game::level::resources::GameMode;
+use crate::main_menu::components::{MainMenu, PlayButton, DifficultyButton, DifficultyText, GameModeButton, GameModeText, ReplayButton, QuitButton};
 use crate::main_menu::styles::*;
 
 pub fn spawn_main_menu(
     mut commands: Commands,
-    asset_server: Res<AssetServer>
+    asset_server: Res<AssetServer>,
+    difficulty: Res<Difficulty>,
+    game_mode: Res<GameMode>
 ) {
-    build_main_menu(&mut commands, &asset_server);
+    build_main_menu(&mut commands, &asset_server, *difficulty, *game_mode);
 }
 
 pub fn despawn_main_menu(
@@ -21,7 +25,9 @@ pub fn despawn_main_menu(
 
 pub fn build_main_menu(
     commands: &mut Commands,
-    asset_server: &Res<AssetServer>
+    asset_server: &Res<AssetServer>,
+    difficulty: Difficulty,
+    game_mode: GameMode
 ) -> Entity {
     let main_menu_entity = commands
         .spawn(
@@ -69,6 +75,44 @@ pub fn build_main_menu(
         ).with_children(|parent| {
             parent.spawn(draw_text(asset_server, "Play".to_string(), 32.0));
         });
+        parent.spawn(draw_text(asset_server, "Difficulty".to_string(), 24.0));
+        parent.spawn(
+            (
+                ButtonBundle {
+                    style: BUTTON_STYLE,
+                    background_color: NORMAL_BUTTON_COLOR.into(),
+                    ..Default::default()
+                },
+                DifficultyButton {}
+            )
+        ).with_children(|parent| {
+            parent.spawn((draw_text(asset_server, difficulty.name().to_string(), 32.0), DifficultyText {}));
+        });
+        parent.spawn(draw_text(asset_server, "Mode".to_string(), 24.0));
+        parent.spawn(
+            (
+                ButtonBundle {
+                    style: BUTTON_STYLE,
+                    background_color: NORMAL_BUTTON_COLOR.into(),
+                    ..Default::default()
+                },
+                GameModeButton {}
+            )
+        ).with_children(|parent| {
+            parent.spawn((draw_text(asset_server, game_mode.name().to_string(), 32.0), GameModeText {}));
+        });
+        parent.spawn(
+            (
+                ButtonBundle {
+                    style: BUTTON_STYLE,
+                    background_color: NORMAL_BUTTON_COLOR.into(),
+                    ..Default::default()
+                },
+                ReplayButton {}
+            )
+        ).with_children(|parent| {
+            parent.spawn(draw_text(asset_server, "Replay".to_string(), 32.0));
+        });
         parent.spawn(
             (
                 ButtonBundle {
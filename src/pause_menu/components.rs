@@ -0,0 +1,31 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct PauseMenu {}
+
+#[derive(Component)]
+pub struct PauseButtons {}
+
+#[derive(Component)]
+pub struct SettingsPanel {}
+
+#[derive(Component)]
+pub struct ResumeButton {}
+
+#[derive(Component)]
+pub struct RestartButton {}
+
+#[derive(Component)]
+pub struct SettingsButton {}
+
+#[derive(Component)]
+pub struct MainMenuButton {}
+
+#[derive(Component)]
+pub struct SoundButton {}
+
+#[derive(Component)]
+pub struct SoundText {}
+
+#[derive(Component)]
+pub struct BackButton {}
@@ -0,0 +1,38 @@
+use bevy::{prelude::*, input::InputSystem};
+
+use crate::AppState;
+use crate::game::SimulationState;
+
+use self::resources::{ResumeRequested, RestartRequested};
+use self::systems::layout::{spawn_pause_menu, despawn_pause_menu};
+use self::systems::interactions::{
+    interact_with_resume_button, interact_with_restart_button, interact_with_settings_button,
+    interact_with_main_menu_button, interact_with_sound_button, interact_with_back_button
+};
+use self::systems::restart::{press_resume_key, restart_game};
+
+mod components;
+mod resources;
+mod systems;
+
+/// Overlay shown whenever the simulation is paused, including the pause at the start of every run.
+pub struct PauseMenuPlugin;
+
+impl Plugin for PauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(SimulationState::Paused), spawn_pause_menu.run_if(in_state(AppState::Game)));
+        app.add_systems(Update, (
+            interact_with_resume_button, interact_with_restart_button, interact_with_settings_button,
+            interact_with_main_menu_button, interact_with_back_button,
+            interact_with_sound_button.run_if(resource_exists::<GlobalVolume>())
+        ).run_if(in_state(AppState::Game)).run_if(in_state(SimulationState::Paused)));
+        app.add_systems(PreUpdate, press_resume_key
+            .after(InputSystem)
+            .run_if(resource_exists::<ResumeRequested>()));
+        app.add_systems(Update, restart_game
+            .run_if(in_state(AppState::Game))
+            .run_if(resource_exists::<RestartRequested>()));
+        app.add_systems(OnExit(SimulationState::Paused), despawn_pause_menu);
+        app.add_systems(OnExit(AppState::Game), despawn_pause_menu);
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+pub const PAUSE_HINT: &str = "Press Space to resume";
+
+/// Set by the Resume button; turned into a Space press on the next frame so recordings pick it up.
+#[derive(Resource, Default)]
+pub struct ResumeRequested {}
+
+/// Set by the Restart button; the current run is torn down and a fresh one started on the same frame.
+#[derive(Resource, Default)]
+pub struct RestartRequested {}
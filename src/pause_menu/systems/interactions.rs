@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::pause_menu::components::*;
+use crate::pause_menu::resources::{ResumeRequested, RestartRequested};
+use crate::pause_menu::systems::layout::{sound_enabled, sound_text};
+use crate::main_menu::styles::{NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR, PRESSED_BUTTON_COLOR};
+
+type ButtonInteraction<'a> = (&'a Interaction, &'a mut BackgroundColor);
+
+/// Applies the shared hover/press colors and reports whether the button was just pressed.
+fn update_button_color(interaction: &Interaction, background_color: &mut BackgroundColor) -> bool {
+    match *interaction {
+        Interaction::Pressed => { *background_color = PRESSED_BUTTON_COLOR.into(); true }
+        Interaction::Hovered => { *background_color = HOVERED_BUTTON_COLOR.into(); false }
+        Interaction::None => { *background_color = NORMAL_BUTTON_COLOR.into(); false }
+    }
+}
+
+pub fn interact_with_resume_button(
+    mut commands: Commands,
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<ResumeButton>)>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        if update_button_color(interaction, &mut background_color) {
+            commands.init_resource::<ResumeRequested>();
+        }
+    }
+}
+
+pub fn interact_with_restart_button(
+    mut commands: Commands,
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<RestartButton>)>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        if update_button_color(interaction, &mut background_color) {
+            commands.init_resource::<RestartRequested>();
+        }
+    }
+}
+
+pub fn interact_with_main_menu_button(
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<MainMenuButton>)>,
+    mut app_state_next_state: ResMut<NextState<AppState>>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        if update_button_color(interaction, &mut background_color) {
+            app_state_next_state.set(AppState::MainMenu);
+        }
+    }
+}
+
+pub fn interact_with_settings_button(
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<SettingsButton>)>,
+    mut buttons_query: Query<&mut Style, (With<PauseButtons>, Without<SettingsPanel>)>,
+    mut settings_query: Query<&mut Style, (With<SettingsPanel>, Without<PauseButtons>)>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        if update_button_color(interaction, &mut background_color) {
+            if let Ok(mut buttons_style) = buttons_query.get_single_mut() { buttons_style.display = Display::None; }
+            if let Ok(mut settings_style) = settings_query.get_single_mut() { settings_style.display = Display::Flex; }
+        }
+    }
+}
+
+pub fn interact_with_back_button(
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<BackButton>)>,
+    mut buttons_query: Query<&mut Style, (With<PauseButtons>, Without<SettingsPanel>)>,
+    mut settings_query: Query<&mut Style, (With<SettingsPanel>, Without<PauseButtons>)>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        if update_button_color(interaction, &mut background_color) {
+            if let Ok(mut buttons_style) = buttons_query.get_single_mut() { buttons_style.display = Display::Flex; }
+            if let Ok(mut settings_style) = settings_query.get_single_mut() { settings_style.display = Display::None; }
+        }
+    }
+}
+
+pub fn interact_with_sound_button(
+    mut button_query: Query<ButtonInteraction, (Changed<Interaction>, With<SoundButton>)>,
+    mut text_query: Query<&mut Text, With<SoundText>>,
+    mut global_volume: ResMut<GlobalVolume>
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        if update_button_color(interaction, &mut background_color) {
+            let sound_on = !sound_enabled(&global_volume);
+            *global_volume = GlobalVolume::new(if sound_on { 1.0 } else { 0.0 });
+            println!("{}", sound_text(sound_on));
+
+            if let Ok(mut text) = text_query.get_single_mut() {
+                text.sections[0].value = sound_text(sound_on);
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+use crate::pause_menu::components::*;
+use crate::pause_menu::resources::PAUSE_HINT;
+use crate::main_menu::styles::*;
+
+pub const PAUSE_MENU_BACKGROUND_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.6);
+
+pub fn spawn_pause_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    global_volume: Option<Res<GlobalVolume>>
+) {
+    let sound_on = match global_volume {
+        Some(global_volume) => sound_enabled(&global_volume),
+        None => true
+    };
+    build_pause_menu(&mut commands, &asset_server, sound_on);
+}
+
+pub fn despawn_pause_menu(
+    mut commands: Commands,
+    pause_menu_query: Query<Entity, With<PauseMenu>>
+) {
+    if let Ok(pause_menu_entity) = pause_menu_query.get_single() {
+        commands.entity(pause_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn sound_enabled(global_volume: &GlobalVolume) -> bool {
+    global_volume.volume.get() > 0.0
+}
+
+pub fn sound_text(sound_on: bool) -> String {
+    if sound_on { "Sound: On".to_string() } else { "Sound: Off".to_string() }
+}
+
+fn spawn_button<B: Component>(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    label: &str,
+    button: B
+) {
+    parent.spawn(
+        (
+            ButtonBundle {
+                style: BUTTON_STYLE,
+                background_color: NORMAL_BUTTON_COLOR.into(),
+                ..Default::default()
+            },
+            button
+        )
+    ).with_children(|parent| {
+        parent.spawn(draw_text(asset_server, label.to_string(), 32.0));
+    });
+}
+
+pub fn build_pause_menu(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    sound_on: bool
+) -> Entity {
+    let column_style = Style {
+        flex_direction: FlexDirection::Column,
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        row_gap: Val::Px(8.0),
+        ..default()
+    };
+
+    let pause_menu_entity = commands
+        .spawn(
+            (NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                background_color: PAUSE_MENU_BACKGROUND_COLOR.into(),
+                z_index: ZIndex::Global(1),
+                ..default()
+            },
+            PauseMenu {}
+        )
+    )
+    .with_children(|parent| {
+        parent.spawn(draw_text(asset_server, "Paused".to_string(), 64.0));
+        parent.spawn(draw_text(asset_server, PAUSE_HINT.to_string(), 24.0));
+        parent.spawn(
+            (NodeBundle { style: column_style.clone(), ..default() }, PauseButtons {})
+        ).with_children(|parent| {
+            spawn_button(parent, asset_server, "Resume", ResumeButton {});
+            spawn_button(parent, asset_server, "Restart", RestartButton {});
+            spawn_button(parent, asset_server, "Settings", SettingsButton {});
+            spawn_button(parent, asset_server, "Main Menu", MainMenuButton {});
+        });
+        parent.spawn(
+            (NodeBundle {
+                style: Style { display: Display::None, ..column_style },
+                ..default()
+            },
+            SettingsPanel {})
+        ).with_children(|parent| {
+            parent.spawn(
+                (
+                    ButtonBundle {
+                        style: BUTTON_STYLE,
+                        background_color: NORMAL_BUTTON_COLOR.into(),
+                        ..Default::default()
+                    },
+                    SoundButton {}
+                )
+            ).with_children(|parent| {
+                parent.spawn((draw_text(asset_server, sound_text(sound_on), 32.0), SoundText {}));
+            });
+            spawn_button(parent, asset_server, "Back", BackButton {});
+        });
+    })
+    .id();
+
+    pause_menu_entity
+}
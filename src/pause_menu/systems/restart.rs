@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::game::SimulationState;
+use crate::pause_menu::resources::{ResumeRequested, RestartRequested};
+
+/// Resumes the simulation through a Space press rather than setting `SimulationState` directly,
+/// so the resume is captured in recordings like any other key.
+pub fn press_resume_key(
+    mut commands: Commands,
+    mut keyboard_input: ResMut<Input<KeyCode>>
+) {
+    commands.remove_resource::<ResumeRequested>();
+    keyboard_input.press(KeyCode::Space);
+    keyboard_input.release(KeyCode::Space);
+}
+
+/// Moving from `AppState::Game` to itself is ignored by bevy, so a restart runs the
+/// exit and enter schedules by hand to tear the current run down and start a fresh one.
+/// The fresh run starts paused, but as the simulation already is, bevy will not enter
+/// `SimulationState::Paused` again, so the overlay removed with the old run is brought back here.
+pub fn restart_game(world: &mut World) {
+    world.remove_resource::<RestartRequested>();
+    println!("Restarting game");
+    world.run_schedule(OnExit(AppState::Game));
+    world.run_schedule(OnEnter(AppState::Game));
+
+    if world.resource::<State<SimulationState>>().get() == &SimulationState::Paused {
+        world.run_schedule(OnEnter(SimulationState::Paused));
+    }
+}
@@ -0,0 +1,12 @@
+use std::path::PathBuf;
+
+pub const DATA_DIRECTORY_NAME: &str = "bevy-ball-game";
+
+/// Location of a file in the user's data directory, falling back to the working directory
+/// on platforms where no data directory is known.
+pub fn data_file(file_name: &str) -> PathBuf {
+    dirs::data_dir()
+        .map(|directory| directory.join(DATA_DIRECTORY_NAME))
+        .unwrap_or_default()
+        .join(file_name)
+}
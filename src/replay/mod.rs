@@ -0,0 +1,54 @@
+use bevy::{prelude::*, ecs::schedule::ExecutorKind, input::InputSystem, time::TimeSystem};
+
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+use crate::AppState;
+use crate::game::{RngSystemSet, config::ConfigSystemSet};
+
+pub use systems::begin_playback;
+
+/// Records every game session (seed, fixed timestep, game config and its hot reloads, per-frame delta and key state) and plays recordings
+/// back by feeding the same deltas and keys into `Time` and `Input<KeyCode>`.
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        // AppState and SimulationState transitions have to apply in the same order on every run,
+        // otherwise a replay can start one frame earlier or later than its recording did.
+        app.edit_schedule(StateTransition, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        });
+
+        app.init_resource::<ReplaySettings>()
+            .add_systems(Startup, apply_replay_args)
+            .add_systems(OnEnter(AppState::Game), (
+                start_recording.run_if(not(resource_exists::<ReplayPlayback>())),
+                restore_replay_conditions.run_if(resource_exists::<ReplayPlayback>())
+            ).after(RngSystemSet::Seed).in_set(ConfigSystemSet))
+            .add_systems(First, apply_replay_frame_time
+                .before(TimeSystem)
+                .run_if(resource_exists::<ReplayPlayback>()))
+            .add_systems(PreUpdate, (
+                apply_replay_frame_input.after(InputSystem),
+                apply_replay_config_change.in_set(ConfigSystemSet)
+            ).run_if(resource_exists::<ReplayPlayback>()))
+            .add_systems(PreUpdate, record_config_change
+                .after(ConfigSystemSet)
+                .run_if(in_state(AppState::Game))
+                .run_if(resource_exists::<ReplayRecorder>()))
+            .add_systems(Update, (
+                (record_frame, record_final_score)
+                    .run_if(in_state(AppState::Game))
+                    .run_if(resource_exists::<ReplayRecorder>()),
+                verify_replay.run_if(resource_exists::<ReplayPlayback>())
+            ))
+            .add_systems(Last, advance_replay
+                .run_if(in_state(AppState::Game))
+                .run_if(resource_exists::<ReplayPlayback>()))
+            .add_systems(OnExit(AppState::Game), (stop_recording, finish_playback));
+    }
+}
@@ -0,0 +1,249 @@
+use std::{fs, path::{Path, PathBuf}, time::Duration};
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use serde::{Deserialize, Serialize};
+
+use crate::game::config::resources::GameConfig;
+use crate::game::level::resources::{GameMode, Level, Levels};
+
+pub const REPLAY_VERSION: u32 = 14;
+pub const LAST_REPLAY_PATH: &str = "replays/last.ron";
+
+/// Keys captured every frame. A key's index in this list is its bit in the `ReplayFrame` masks.
+pub const RECORDED_KEYS: [KeyCode; 9] = [
+    KeyCode::Left, KeyCode::A, KeyCode::Right, KeyCode::D,
+    KeyCode::Up, KeyCode::W, KeyCode::Down, KeyCode::S,
+    KeyCode::Space
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+pub struct ReplayFrame {
+    pub delta_nanos: u64,
+    pub pressed: u16,
+    pub just_pressed: u16
+}
+
+impl ReplayFrame {
+    pub fn capture(delta: Duration, keyboard_input: &Input<KeyCode>) -> ReplayFrame {
+        let mut frame = ReplayFrame { delta_nanos: delta.as_nanos() as u64, ..default() };
+
+        for (bit, key) in RECORDED_KEYS.iter().enumerate() {
+            if keyboard_input.pressed(*key) { frame.pressed |= 1 << bit; }
+            if keyboard_input.just_pressed(*key) { frame.just_pressed |= 1 << bit; }
+        }
+        frame
+    }
+
+    /// Puts the keys in their recorded state. A key that was only just pressed was tapped
+    /// (pressed and released within the frame) and is applied the same way.
+    pub fn apply(&self, keyboard_input: &mut Input<KeyCode>) {
+        for (bit, key) in RECORDED_KEYS.iter().enumerate() {
+            let pressed = self.pressed & (1 << bit) != 0;
+            let just_pressed = self.just_pressed & (1 << bit) != 0;
+
+            keyboard_input.reset(*key);
+            if pressed || just_pressed { keyboard_input.press(*key); }
+            if !just_pressed { keyboard_input.clear_just_pressed(*key); }
+            if !pressed { keyboard_input.release(*key); }
+        }
+    }
+
+    pub fn delta(&self) -> Duration {
+        Duration::from_nanos(self.delta_nanos)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Replay {
+    pub version: u32,
+    pub seed: u64,
+    pub timestep_nanos: u64,
+    pub game_config: GameConfig,
+    /// Config hot-reloaded during the run, keyed by the frame it took effect on.
+    pub config_changes: Vec<(usize, GameConfig)>,
+    pub game_mode: GameMode,
+    /// Levels played in level mode; empty for endless runs.
+    pub levels: Vec<Level>,
+    pub final_score: Option<u32>,
+    pub frames: Vec<ReplayFrame>
+}
+
+impl Replay {
+    pub fn new(seed: u64, timestep: Duration, game_config: GameConfig, game_mode: GameMode, levels: Vec<Level>) -> Replay {
+        Replay {
+            version: REPLAY_VERSION,
+            seed,
+            timestep_nanos: timestep.as_nanos() as u64,
+            game_config,
+            config_changes: Vec::new(),
+            game_mode,
+            levels,
+            final_score: None,
+            frames: Vec::new()
+        }
+    }
+
+    pub fn timestep(&self) -> Duration {
+        Duration::from_nanos(self.timestep_nanos)
+    }
+
+    pub fn load(path: &Path) -> Result<Replay, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|error| format!("could not read replay {}: {}", path.display(), error))?;
+        let replay: Replay = ron::from_str(&contents)
+            .map_err(|error| format!("could not parse replay {}: {}", path.display(), error))?;
+
+        if replay.version != REPLAY_VERSION {
+            return Err(format!("replay {} has version {}, expected {}", path.display(), replay.version, REPLAY_VERSION));
+        }
+        Ok(replay)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)
+                .map_err(|error| format!("could not create {}: {}", directory.display(), error))?;
+        }
+        let contents = ron::to_string(self)
+            .map_err(|error| format!("could not serialize replay: {}", error))?;
+        fs::write(path, contents)
+            .map_err(|error| format!("could not write replay {}: {}", path.display(), error))
+    }
+}
+
+/// Where the next recorded run is written.
+#[derive(Resource)]
+pub struct ReplaySettings {
+    pub record_path: PathBuf
+}
+
+impl Default for ReplaySettings {
+    fn default() -> ReplaySettings {
+        ReplaySettings { record_path: PathBuf::from(LAST_REPLAY_PATH) }
+    }
+}
+
+#[derive(Resource)]
+pub struct ReplayRecorder {
+    pub replay: Replay
+}
+
+#[derive(Resource)]
+pub struct ReplayPlayback {
+    pub replay: Replay,
+    pub frame: usize,
+    pub finished: bool,
+    pub previous_time_strategy: Option<TimeUpdateStrategy>,
+    pub previous_game_config: Option<GameConfig>,
+    pub previous_levels: Option<(GameMode, Levels)>
+}
+
+impl ReplayPlayback {
+    pub fn new(replay: Replay) -> ReplayPlayback {
+        ReplayPlayback { replay, frame: 0, finished: false, previous_time_strategy: None, previous_game_config: None, previous_levels: None }
+    }
+
+    pub fn current_frame(&self) -> Option<&ReplayFrame> {
+        self.replay.frames.get(self.frame)
+    }
+
+    pub fn current_config_change(&self) -> Option<&GameConfig> {
+        self.replay.config_changes.iter()
+            .find(|(frame, _)| *frame == self.frame)
+            .map(|(_, game_config)| game_config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A file of its own in the temp directory, so tests running in parallel do not share one.
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("bevy-ball-game-{}-{}", std::process::id(), name))
+    }
+
+    fn key_states(keyboard_input: &Input<KeyCode>) -> Vec<(bool, bool)> {
+        RECORDED_KEYS.iter().map(|key| (keyboard_input.pressed(*key), keyboard_input.just_pressed(*key))).collect()
+    }
+
+    #[test]
+    fn played_back_frames_reproduce_the_recorded_keys() {
+        // Per frame: keys pressed, then keys released, before the frame is captured.
+        let script: [(&[KeyCode], &[KeyCode]); 6] = [
+            (&[KeyCode::Left], &[]),
+            (&[KeyCode::Up], &[]),
+            (&[], &[KeyCode::Left]),
+            (&[KeyCode::Space], &[KeyCode::Space]),
+            (&[KeyCode::D, KeyCode::S], &[KeyCode::Up]),
+            (&[], &[KeyCode::D, KeyCode::S])
+        ];
+
+        let mut recorded_input = Input::<KeyCode>::default();
+        let mut recorded = Vec::new();
+        let mut frames = Vec::new();
+        for (index, (pressed, released)) in script.iter().enumerate() {
+            recorded_input.clear();
+            for key in pressed.iter() { recorded_input.press(*key); }
+            for key in released.iter() { recorded_input.release(*key); }
+            recorded.push(key_states(&recorded_input));
+            frames.push(ReplayFrame::capture(Duration::from_millis(16 + index as u64), &recorded_input));
+        }
+
+        let mut played_input = Input::<KeyCode>::default();
+        for (index, frame) in frames.iter().enumerate() {
+            played_input.clear();
+            frame.apply(&mut played_input);
+            assert_eq!(key_states(&played_input), recorded[index], "frame {}", index);
+            assert_eq!(frame.delta(), Duration::from_millis(16 + index as u64));
+        }
+    }
+
+    #[test]
+    fn a_tap_is_played_back_as_just_pressed_but_not_held() {
+        let mut recorded_input = Input::<KeyCode>::default();
+        recorded_input.press(KeyCode::Space);
+        recorded_input.release(KeyCode::Space);
+        let frame = ReplayFrame::capture(Duration::ZERO, &recorded_input);
+
+        let mut played_input = Input::<KeyCode>::default();
+        frame.apply(&mut played_input);
+        assert!(played_input.just_pressed(KeyCode::Space));
+        assert!(!played_input.pressed(KeyCode::Space));
+    }
+
+    #[test]
+    fn saved_replays_load_back_unchanged() {
+        let path = temp_path("replay-round-trip.ron");
+        let mut game_config = GameConfig::default();
+        let mut replay = Replay::new(42, Duration::from_nanos(16_666_667), game_config.clone(), GameMode::Endless, Vec::new());
+        game_config.enemy.speed = 300.0;
+        replay.config_changes.push((2, game_config));
+        replay.frames = vec![
+            ReplayFrame { delta_nanos: 16_000_000, pressed: 0b1, just_pressed: 0b1 },
+            ReplayFrame { delta_nanos: 17_000_000, pressed: 0b1, just_pressed: 0 },
+            ReplayFrame { delta_nanos: 15_000_000, pressed: 0, just_pressed: 1 << 8 }
+        ];
+        replay.final_score = Some(7);
+
+        replay.save(&path).unwrap();
+        let loaded = Replay::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(ron::to_string(&loaded).unwrap(), ron::to_string(&replay).unwrap());
+        assert_eq!(loaded.timestep(), Duration::from_nanos(16_666_667));
+    }
+
+    #[test]
+    fn rejects_replays_from_other_versions() {
+        let path = temp_path("replay-old-version.ron");
+        let mut replay = Replay::new(1, Duration::from_millis(16), GameConfig::default(), GameMode::Levels, Vec::new());
+        replay.version = REPLAY_VERSION - 1;
+
+        replay.save(&path).unwrap();
+        let error = Replay::load(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(error.contains(&format!("expected {}", REPLAY_VERSION)), "{}", error);
+    }
+}
@@ -0,0 +1,204 @@
+use std::path::Path;
+
+use bevy::{prelude::*, app::AppExit, time::TimeUpdateStrategy};
+
+use super::resources::*;
+use crate::{args::LaunchArgs, events::{GameConfigReloaded, GameOver}, AppState};
+use crate::game::{GAMEPLAY_TIMESTEP_HZ, config::resources::GameConfig, resources::GameRng};
+use crate::game::level::resources::{GameMode, Levels};
+
+pub fn begin_playback(
+    commands: &mut Commands,
+    next_app_state: &mut NextState<AppState>,
+    path: &Path
+) {
+    match Replay::load(path) {
+        Ok(replay) => {
+            println!("Playing replay {} ({} frames, seed {})", path.display(), replay.frames.len(), replay.seed);
+            commands.insert_resource(ReplayPlayback::new(replay));
+            next_app_state.set(AppState::Game);
+        }
+        Err(error) => println!("Replay unavailable: {}", error)
+    }
+}
+
+pub fn apply_replay_args(
+    mut commands: Commands,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut replay_settings: ResMut<ReplaySettings>,
+    launch_args: Res<LaunchArgs>
+) {
+    if let Some(path) = &launch_args.record {
+        replay_settings.record_path = path.clone();
+    }
+    if let Some(path) = &launch_args.replay {
+        begin_playback(&mut commands, &mut next_app_state, path);
+    }
+}
+
+pub fn start_recording(
+    mut commands: Commands,
+    game_rng: Res<GameRng>,
+    game_config: Res<GameConfig>,
+    game_mode: Res<GameMode>,
+    levels: Res<Levels>,
+    fixed_time: Res<Time<Fixed>>
+) {
+    let recorded_levels = if *game_mode == GameMode::Levels { levels.levels.clone() } else { Vec::new() };
+    let replay = Replay::new(game_rng.seed, fixed_time.timestep(), game_config.clone(), *game_mode, recorded_levels);
+    commands.insert_resource(ReplayRecorder { replay });
+}
+
+pub fn record_frame(
+    mut recorder: ResMut<ReplayRecorder>,
+    keyboard_input: Res<Input<KeyCode>>,
+    time: Res<Time>
+) {
+    recorder.replay.frames.push(ReplayFrame::capture(time.delta(), &keyboard_input));
+}
+
+pub fn record_config_change(
+    mut reloaded_event_reader: EventReader<GameConfigReloaded>,
+    mut recorder: ResMut<ReplayRecorder>,
+    game_config: Res<GameConfig>
+) {
+    for _ in reloaded_event_reader.read() {
+        let frame = recorder.replay.frames.len();
+        recorder.replay.config_changes.push((frame, game_config.clone()));
+    }
+}
+
+pub fn record_final_score(
+    mut game_over_event_reader: EventReader<GameOver>,
+    mut recorder: ResMut<ReplayRecorder>
+) {
+    for event in game_over_event_reader.read() {
+        recorder.replay.final_score = Some(event.score);
+    }
+}
+
+pub fn stop_recording(
+    mut commands: Commands,
+    recorder: Option<Res<ReplayRecorder>>,
+    replay_settings: Res<ReplaySettings>
+) {
+    if let Some(recorder) = recorder {
+        match recorder.replay.save(&replay_settings.record_path) {
+            Ok(()) => println!("Replay saved to {}", replay_settings.record_path.display()),
+            Err(error) => println!("Replay not saved: {}", error)
+        }
+        commands.remove_resource::<ReplayRecorder>();
+    }
+}
+
+pub fn restore_replay_conditions(
+    mut playback: ResMut<ReplayPlayback>,
+    mut game_rng: ResMut<GameRng>,
+    mut game_config: ResMut<GameConfig>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut game_mode: ResMut<GameMode>,
+    mut levels: ResMut<Levels>
+) {
+    *game_rng = GameRng::from_seed(playback.replay.seed);
+    fixed_time.set_timestep(playback.replay.timestep());
+
+    let recorded_config = playback.replay.game_config.clone();
+    playback.previous_game_config = Some(std::mem::replace(&mut *game_config, recorded_config));
+
+    let recorded_levels = Levels { levels: playback.replay.levels.clone() };
+    let previous_game_mode = std::mem::replace(&mut *game_mode, playback.replay.game_mode);
+    playback.previous_levels = Some((previous_game_mode, std::mem::replace(&mut *levels, recorded_levels)));
+}
+
+pub fn apply_replay_frame_time(
+    mut playback: ResMut<ReplayPlayback>,
+    mut time_update_strategy: ResMut<TimeUpdateStrategy>
+) {
+    if let Some(frame) = playback.current_frame() {
+        let strategy = TimeUpdateStrategy::ManualDuration(frame.delta());
+        let previous = std::mem::replace(&mut *time_update_strategy, strategy);
+
+        if playback.previous_time_strategy.is_none() {
+            playback.previous_time_strategy = Some(previous);
+        }
+    }
+}
+
+pub fn apply_replay_frame_input(
+    playback: Res<ReplayPlayback>,
+    mut keyboard_input: ResMut<Input<KeyCode>>
+) {
+    if let Some(frame) = playback.current_frame() {
+        frame.apply(&mut keyboard_input);
+    }
+}
+
+pub fn apply_replay_config_change(
+    playback: Res<ReplayPlayback>,
+    mut game_config: ResMut<GameConfig>
+) {
+    if let Some(recorded_config) = playback.current_config_change() {
+        *game_config = recorded_config.clone();
+    }
+}
+
+pub fn advance_replay(
+    mut playback: ResMut<ReplayPlayback>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut app_exit_event_writer: EventWriter<AppExit>,
+    launch_args: Res<LaunchArgs>
+) {
+    playback.frame += 1;
+
+    if playback.current_frame().is_none() && !playback.finished {
+        println!("Replay ended before the game was over");
+        if launch_args.headless {
+            app_exit_event_writer.send(AppExit);
+        } else {
+            next_app_state.set(AppState::MainMenu);
+        }
+    }
+}
+
+pub fn verify_replay(
+    mut game_over_event_reader: EventReader<GameOver>,
+    mut playback: ResMut<ReplayPlayback>
+) {
+    for event in game_over_event_reader.read() {
+        playback.finished = true;
+        match playback.replay.final_score {
+            Some(expected) if expected == event.score => {
+                println!("Replay verified: final score {} matches the recording", event.score);
+            }
+            Some(expected) => {
+                println!("Replay diverged: final score {}, recording ended with {}", event.score, expected);
+            }
+            None => println!("Replay finished with score {}; the recording has no final score", event.score)
+        }
+    }
+}
+
+pub fn finish_playback(
+    mut commands: Commands,
+    playback: Option<ResMut<ReplayPlayback>>,
+    mut time_update_strategy: ResMut<TimeUpdateStrategy>,
+    mut game_config: ResMut<GameConfig>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut game_mode: ResMut<GameMode>,
+    mut levels: ResMut<Levels>
+) {
+    if let Some(mut playback) = playback {
+        if let Some(previous) = playback.previous_time_strategy.take() {
+            *time_update_strategy = previous;
+        }
+        if let Some(previous) = playback.previous_game_config.take() {
+            *game_config = previous;
+        }
+        if let Some((previous_game_mode, previous_levels)) = playback.previous_levels.take() {
+            *game_mode = previous_game_mode;
+            *levels = previous_levels;
+        }
+        fixed_time.set_timestep_hz(GAMEPLAY_TIMESTEP_HZ);
+        commands.remove_resource::<ReplayPlayback>();
+    }
+}
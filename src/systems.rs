@@ -1,31 +1,46 @@
-use bevy::{prelude::*, window::PrimaryWindow, app::AppExit};
+use bevy::{prelude::*, app::AppExit, render::camera::ScalingMode};
 
 use crate::{events::*, AppState};
+use crate::game::resources::Playfield;
 
 pub fn spawn_camera(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>
+    playfield: Res<Playfield>
 ) {
-    let window = window_query.get_single().unwrap();
-
     commands.spawn(
         Camera2dBundle {
-            transform: Transform::from_xyz(window.width() / 2.0, window.height() / 2.0, 0.0),
+            transform: Transform::from_translation(playfield.center().extend(0.0)),
+            projection: OrthographicProjection {
+                scaling_mode: ScalingMode::AutoMin { min_width: playfield.size.x, min_height: playfield.size.y },
+                near: -1000.0,
+                far: 1000.0,
+                ..default()
+            },
             ..default()
         }
     );
 }
 
+pub fn fit_camera_to_playfield(
+    playfield: Res<Playfield>,
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<Camera2d>>
+) {
+    if !playfield.is_changed() { return; }
+
+    for (mut transform, mut projection) in camera_query.iter_mut() {
+        transform.translation = playfield.center().extend(transform.translation.z);
+        projection.scaling_mode = ScalingMode::AutoMin { min_width: playfield.size.x, min_height: playfield.size.y };
+    }
+}
+
 pub fn transition_to_game_state(
     keyboard_input: Res<Input<KeyCode>>,
     app_state: Res<State<AppState>>,
     mut next_app_state: ResMut<NextState<AppState>>
 ) {
-    if keyboard_input.just_pressed(KeyCode::G) {
-        if app_state.get() != &AppState::Game {
-            next_app_state.set(AppState::Game);
-            println!("AppState: game");
-        }
+    if keyboard_input.just_pressed(KeyCode::G) && app_state.get() != &AppState::Game {
+        next_app_state.set(AppState::Game);
+        println!("AppState: game");
     }
 }
 
@@ -34,11 +49,9 @@ pub fn transition_to_main_menu_state(
     app_state: Res<State<AppState>>,
     mut next_app_state: ResMut<NextState<AppState>>
 ) {
-    if keyboard_input.just_pressed(KeyCode::M) {
-        if app_state.get() != &AppState::MainMenu {
-            next_app_state.set(AppState::MainMenu);
-            println!("AppState: main menu");
-        }
+    if keyboard_input.just_pressed(KeyCode::M) && app_state.get() != &AppState::MainMenu {
+        next_app_state.set(AppState::MainMenu);
+        println!("AppState: main menu");
     }
 }
 
@@ -56,7 +69,7 @@ pub fn handle_game_over(
     mut next_app_state: ResMut<NextState<AppState>>
 ) {
     for event in game_over_event_reader.read() {
-        println!("FINAL SCORE: {}", event.score.to_string());
+        println!("FINAL SCORE: {}", event.score);
         next_app_state.set(AppState::GameOver);
     }
 }